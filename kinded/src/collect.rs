@@ -0,0 +1,65 @@
+use alloc::vec::Vec;
+use core::hash::Hash;
+use std::collections::HashMap;
+
+use crate::Kinded;
+
+/// Count how many items of each kind the iterator yields.
+/// Kinds without any items are absent from the map.
+///
+/// ```
+/// use kinded::{count_by_kind, Kinded};
+///
+/// #[derive(Kinded)]
+/// enum Drink {
+///     Mate,
+///     Coffee(String),
+/// }
+///
+/// let drinks = vec![Drink::Mate, Drink::Coffee("Espresso".to_owned()), Drink::Mate];
+/// let counts = count_by_kind(drinks);
+/// assert_eq!(counts[&DrinkKind::Mate], 2);
+/// assert_eq!(counts[&DrinkKind::Coffee], 1);
+/// ```
+pub fn count_by_kind<I>(iter: I) -> HashMap<<I::Item as Kinded>::Kind, usize>
+where
+    I: IntoIterator,
+    I::Item: Kinded,
+    <I::Item as Kinded>::Kind: Hash,
+{
+    let mut counts = HashMap::new();
+    for item in iter {
+        *counts.entry(item.kind()).or_insert(0) += 1;
+    }
+    counts
+}
+
+/// Group the items of the iterator by their kind.
+/// Within each group the items keep the order of the iterator.
+///
+/// ```
+/// use kinded::{group_by_kind, Kinded};
+///
+/// #[derive(Kinded, Debug, PartialEq)]
+/// enum Drink {
+///     Mate,
+///     Coffee(&'static str),
+/// }
+///
+/// let drinks = vec![Drink::Coffee("Espresso"), Drink::Mate, Drink::Coffee("Latte")];
+/// let groups = group_by_kind(drinks);
+/// assert_eq!(groups[&DrinkKind::Coffee], [Drink::Coffee("Espresso"), Drink::Coffee("Latte")]);
+/// assert_eq!(groups[&DrinkKind::Mate], [Drink::Mate]);
+/// ```
+pub fn group_by_kind<I>(iter: I) -> HashMap<<I::Item as Kinded>::Kind, Vec<I::Item>>
+where
+    I: IntoIterator,
+    I::Item: Kinded,
+    <I::Item as Kinded>::Kind: Hash,
+{
+    let mut groups: HashMap<_, Vec<_>> = HashMap::new();
+    for item in iter {
+        groups.entry(item.kind()).or_default().push(item);
+    }
+    groups
+}
@@ -0,0 +1,42 @@
+use ::core::marker::PhantomData;
+
+/// This type is used by `kinded` macro to implement `TryFrom<DrinkKind>` for the main enum
+/// and is not recommend for a direct usage by users.
+///
+/// It detects at compile time whether a field type implements `Default` using autoref
+/// specialization: `ProbeDefault` is implemented for the probe itself and is preferred
+/// by the method resolution, `ProbeNoDefault` is implemented for a reference to the probe
+/// and is picked only when the field type does not implement `Default`.
+pub struct DefaultProbe<T>(PhantomData<T>);
+
+impl<T> DefaultProbe<T> {
+    pub fn new() -> Self {
+        DefaultProbe(PhantomData)
+    }
+}
+
+impl<T> Default for DefaultProbe<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub trait ProbeDefault<T> {
+    fn probe_default(&self) -> Option<T>;
+}
+
+impl<T: Default> ProbeDefault<T> for DefaultProbe<T> {
+    fn probe_default(&self) -> Option<T> {
+        Some(T::default())
+    }
+}
+
+pub trait ProbeNoDefault<T> {
+    fn probe_default(&self) -> Option<T>;
+}
+
+impl<T> ProbeNoDefault<T> for &DefaultProbe<T> {
+    fn probe_default(&self) -> Option<T> {
+        None
+    }
+}
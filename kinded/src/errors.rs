@@ -6,6 +6,7 @@ use alloc::string::{String, ToString};
 pub struct ParseKindError {
     kind_type_name: String,
     given_string: String,
+    valid_names: &'static [&'static str],
 }
 
 impl ParseKindError {
@@ -21,8 +22,23 @@ impl ParseKindError {
         ParseKindError {
             kind_type_name,
             given_string,
+            valid_names: &[],
         }
     }
+
+    /// This method is used by `kinded` macro to attach the display names of the kind variants,
+    /// which are listed in the error message.
+    pub fn with_valid_names(self, valid_names: &'static [&'static str]) -> ParseKindError {
+        ParseKindError {
+            valid_names,
+            ..self
+        }
+    }
+
+    /// Display names of the kind variants, which would be parsed successfully.
+    pub fn valid_names(&self) -> &'static [&'static str] {
+        self.valid_names
+    }
 }
 
 impl ::core::fmt::Display for ParseKindError {
@@ -30,8 +46,17 @@ impl ::core::fmt::Display for ParseKindError {
         let Self {
             kind_type_name,
             given_string,
+            valid_names,
         } = self;
-        write!(f, r#"Failed to parse "{given_string}" as {kind_type_name}"#)
+        write!(f, r#"Failed to parse "{given_string}" as {kind_type_name}"#)?;
+        if let Some((first_name, other_names)) = valid_names.split_first() {
+            write!(f, " (expected one of: {first_name}")?;
+            for name in other_names {
+                write!(f, ", {name}")?;
+            }
+            write!(f, ")")?;
+        }
+        Ok(())
     }
 }
 
@@ -40,3 +65,6 @@ impl ::core::fmt::Debug for ParseKindError {
         write!(f, "ParseKindError: {self}")
     }
 }
+
+#[cfg(feature = "std")]
+impl ::std::error::Error for ParseKindError {}
@@ -0,0 +1,12 @@
+/// Shape of the fields of a variant, returned by `fields_type()` method of a kind type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FieldsType {
+    /// Example: `Tea { variety: String }`
+    Named,
+
+    /// Example: `Coffee(String)`
+    Unnamed,
+
+    /// Example: `Mate`
+    Unit,
+}
@@ -36,89 +36,860 @@
 //!         Drink::Tea { .. } => DrinkKind::Tea,
 //!     }
 //! }
+//! ```
+//!
+//! ## Check the kind
+//!
+//! `is_kind()` method checks whether a value is of the given kind, which reads nicely in iterator chains:
+//!
+//! ```
+//! # use kinded::Kinded;
+//! # #[derive(Kinded)]
+//! # enum Drink { Mate, Coffee(String), Tea { variety: String, caffeine: bool } }
+//! let drinks = vec![Drink::Mate, Drink::Coffee("Espresso".to_owned()), Drink::Mate];
+//! let mates = drinks.iter().filter(|drink| drink.is_kind(DrinkKind::Mate)).count();
+//! assert_eq!(mates, 2);
+//! ```
+//!
+//! When the values are not needed anymore, `into_kind()` consumes them and fits nicely into `map()`:
+//!
+//! ```
+//! # use kinded::Kinded;
+//! # #[derive(Kinded)]
+//! # enum Drink { Mate, Coffee(String), Tea { variety: String, caffeine: bool } }
+//! let drinks = vec![Drink::Mate, Drink::Coffee("Espresso".to_owned())];
+//! let kinds: Vec<DrinkKind> = drinks.into_iter().map(Drink::into_kind).collect();
+//! assert_eq!(kinds, [DrinkKind::Mate, DrinkKind::Coffee]);
+//! ```
+//!
+//! The kind type also gets an `is_<variant>()` predicate for every variant, named after the variant in snake_case:
+//!
+//! ```
+//! # use kinded::Kinded;
+//! # #[derive(Kinded)]
+//! # enum Drink { Mate, Coffee(String), Tea { variety: String, caffeine: bool } }
+//! let kind = DrinkKind::Tea;
+//! assert!(kind.is_tea());
+//! assert!(!kind.is_coffee());
+//! ```
+//!
+//! If two variants map to the same name, e.g. `HttpServer` and `HTTPServer`, neither of them gets the predicate
+//! nor the `<VARIANT>_INDEX` constant, so they can be defined by hand.
+//!
+//! ## Kinded trait
+//!
+//! The library provides `Kinded` trait:
+//!
+//! ```rs
+//! pub trait Kinded {
+//!     type Kind: PartialEq + Eq + Debug + Clone;
+//!
+//!     fn kind(&self) -> Self::Kind;
+//! }
+//! ```
+//!
+//! From the example above, the derived implementation of `Kinded` for `Drink` resembles the following:
+//!
+//! ```ignore
+//! impl Kinded for Drink {
+//!     type Kind = DrinkKind;
+//!
+//!     fn kind(&self) -> DrinkKind { /* implementation */ }
+//! }
+//! ```
+//!
+//! The derived kind types are `Copy`, but the trait doesn't require it, so `Kinded` can be implemented manually
+//! with any type implementing `kinded::Kind` as the kind.
+//!
+//! The `Kinded` trait allows to build abstract functions that can be used with different enum types.
+//! For example, the display name of the kind can be obtained with `kind_name()`:
+//!
+//! ```
+//! use kinded::Kinded;
+//!
+//! fn log_kind<T: Kinded>(value: &T)
+//! where
+//!     T::Kind: Into<&'static str>,
+//! {
+//!     println!("Got {}", value.kind_name());
+//! }
+//! ```
+//!
+//! `Kinded` is also implemented for references, so generic code can take values either way:
+//!
+//! ```
+//! # use kinded::Kinded;
+//! # #[derive(Kinded)]
+//! # enum Drink { Mate, Coffee(String), Tea { variety: String, caffeine: bool } }
+//! fn is_same_kind<T: Kinded>(a: T, b: T) -> bool {
+//!     a.kind() == b.kind()
+//! }
+//!
+//! let mate = Drink::Mate;
+//! let coffee = Drink::Coffee("Espresso".to_owned());
+//! assert!(!is_same_kind(&mate, &coffee));
+//! ```
+//!
+//! All the possible kinds can be enumerated with `all_kinds()`, e.g. to build a table for every kind:
+//!
+//! ```
+//! use kinded::Kinded;
+//!
+//! fn count_kinds<T: Kinded>() -> usize {
+//!     T::all_kinds().len()
+//! }
+//! ```
+//!
+//! With `std` feature (enabled by default) items of an iterator can be counted per kind with `count_by_kind()`:
+//!
+//! ```
+//! # use kinded::Kinded;
+//! # #[derive(Kinded)]
+//! # enum Drink { Mate, Coffee(String), Tea { variety: String, caffeine: bool } }
+//! use kinded::count_by_kind;
+//!
+//! let drinks = vec![Drink::Mate, Drink::Coffee("Espresso".to_owned()), Drink::Mate];
+//! let counts = count_by_kind(drinks);
+//! assert_eq!(counts[&DrinkKind::Mate], 2);
+//! ```
+//!
+//! Similarly, `group_by_kind()` collects the items into groups per kind, keeping their order:
+//!
+//! ```
+//! # use kinded::Kinded;
+//! # #[derive(Kinded)]
+//! # enum Drink { Mate, Coffee(String), Tea { variety: String, caffeine: bool } }
+//! use kinded::group_by_kind;
+//!
+//! let drinks = vec![Drink::Coffee("Espresso".to_owned()), Drink::Mate, Drink::Coffee("Latte".to_owned())];
+//! let groups = group_by_kind(drinks);
+//! assert_eq!(groups[&DrinkKind::Coffee].len(), 2);
+//! ```
+//!
+//! `Kinded` can be derived on structs as well. The kind type of a struct has a single variant named after the struct,
+//! so structs and enums can be handled uniformly in generic code.
+//!
+//! ## Get all kind variants
+//!
+//! The kind type gets implementation of `::all()` associated function, which returns a static slice with all kind variants in the declaration order:
+//!
+//! ```
+//! use kinded::Kinded;
+//!
+//! #[derive(Kinded)]
+//! enum Drink {
+//!     Mate,
+//!     Coffee(String),
+//!     Tea { variety: String, caffeine: bool }
+//! }
+//!
+//! assert_eq!(DrinkKind::all(), [DrinkKind::Mate, DrinkKind::Coffee, DrinkKind::Tea]);
+//! assert_eq!(DrinkKind::all().len(), 3);
+//! ```
+//!
+//! `::iter()` iterates over the same kinds by value. It's a `DoubleEndedIterator` and `ExactSizeIterator`,
+//! so it can be reversed or measured without collecting:
+//!
+//! ```
+//! # use kinded::Kinded;
+//! # #[derive(Kinded)]
+//! # enum Drink { Mate, Coffee(String), Tea { variety: String, caffeine: bool } }
+//! let reversed: Vec<DrinkKind> = DrinkKind::iter().rev().collect();
+//! assert_eq!(reversed, [DrinkKind::Tea, DrinkKind::Coffee, DrinkKind::Mate]);
+//! assert_eq!(DrinkKind::iter().len(), DrinkKind::COUNT);
+//! ```
+//!
+//! `iter()` returns a generated `DrinkKindIter` type rather than `impl Iterator`, so it can be named, e.g. to store it in a struct field:
+//!
+//! ```
+//! # use kinded::Kinded;
+//! # #[derive(Kinded)]
+//! # enum Drink { Mate, Coffee(String), Tea { variety: String, caffeine: bool } }
+//! struct Menu {
+//!     kinds: DrinkKindIter,
+//! }
+//!
+//! let menu = Menu { kinds: DrinkKind::iter() };
+//! assert_eq!(menu.kinds.len(), 3);
+//! ```
+//!
+//! The same variants are available in `const` context as `ALL` array, and their number as `COUNT` constant:
+//!
+//! ```
+//! # use kinded::Kinded;
+//! # #[derive(Kinded)]
+//! # enum Drink { Mate, Coffee(String), Tea { variety: String, caffeine: bool } }
+//! const KINDS: [DrinkKind; DrinkKind::COUNT] = DrinkKind::ALL;
+//! let mut stock = [0u32; DrinkKind::COUNT];
+//! assert_eq!(stock.len(), 3);
+//! ```
+//!
+//! Display names of all the kind variants are available as `NAMES` constant and `::names()` function, which is handy for help texts:
+//!
+//! ```
+//! # use kinded::Kinded;
+//! # #[derive(Kinded)]
+//! # enum Drink { Mate, Coffee(String), Tea { variety: String, caffeine: bool } }
+//! assert_eq!(DrinkKind::names(), ["Mate", "Coffee", "Tea"]);
+//! ```
+//!
+//! Both at once, e.g. to build a documentation table, are available with `all_named()`:
+//!
+//! ```
+//! # use kinded::Kinded;
+//! # #[derive(Kinded)]
+//! # enum Drink { Mate, Coffee(String), Tea { variety: String, caffeine: bool } }
+//! for (kind, name) in DrinkKind::all_named() {
+//!     println!("{name}: {}", kind.arity());
+//! }
+//! ```
+//!
+//! `for_each_kind()` calls a closure with every kind. Together with a `match` inside the closure, the compiler makes sure
+//! that a dispatch table covers all the kinds:
+//!
+//! ```
+//! # use kinded::Kinded;
+//! # #[derive(Kinded)]
+//! # enum Drink { Mate, Coffee(String), Tea { variety: String, caffeine: bool } }
+//! let mut prices = [0; DrinkKind::COUNT];
+//! DrinkKind::for_each_kind(|kind| {
+//!     prices[kind.to_index()] = match kind {
+//!         DrinkKind::Mate => 3,
+//!         DrinkKind::Coffee => 4,
+//!         DrinkKind::Tea => 2,
+//!     };
+//! });
+//! assert_eq!(prices, [3, 4, 2]);
+//! ```
+//!
+//! All the kinds except a given one can be iterated with `others()`, e.g. to list possible transitions of a state machine:
+//!
+//! ```
+//! # use kinded::Kinded;
+//! # #[derive(Kinded)]
+//! # enum Drink { Mate, Coffee(String), Tea { variety: String, caffeine: bool } }
+//! let others: Vec<_> = DrinkKind::Mate.others().collect();
+//! assert_eq!(others, [DrinkKind::Coffee, DrinkKind::Tea]);
+//! ```
+//!
+//! ## Index of a kind
+//!
+//! `to_index()` returns the position of the kind variant in the declaration order, and `from_index()` does the opposite:
+//!
+//! ```
+//! # use kinded::Kinded;
+//! # #[derive(Kinded)]
+//! # enum Drink { Mate, Coffee(String), Tea { variety: String, caffeine: bool } }
+//! assert_eq!(DrinkKind::Coffee.to_index(), 1);
+//! assert_eq!(DrinkKind::from_index(1), Some(DrinkKind::Coffee));
+//! assert_eq!(DrinkKind::from_index(3), None);
+//! ```
+//!
+//! `from_index()` is a `const fn`, which looks the kind up in `INDEX_TO_KIND` table, so the conversion is a single load
+//! after the bounds check.
+//!
+//! The index can be also taken right from a value with `kind_index()`, e.g. to index an array by variant:
+//!
+//! ```
+//! # use kinded::Kinded;
+//! # #[derive(Kinded)]
+//! # enum Drink { Mate, Coffee(String), Tea { variety: String, caffeine: bool } }
+//! let mut stock = [0u32; DrinkKind::COUNT];
+//! stock[Drink::Coffee("Espresso".to_owned()).kind_index()] += 1;
+//! assert_eq!(stock, [0, 1, 0]);
+//! ```
+//!
+//! Every kind variant also gets its index as a constant, e.g. `TEA_INDEX`, so a protocol relying on the indexes
+//! can guard them against accidental reordering at compile time:
+//!
+//! ```
+//! # use kinded::Kinded;
+//! # #[derive(Kinded)]
+//! # enum Drink { Mate, Coffee(String), Tea { variety: String, caffeine: bool } }
+//! const _: () = assert!(DrinkKind::TEA_INDEX == 2);
+//! ```
+//!
+//! The neighbour kinds in the declaration order can be obtained with `next()` and `prev()`, which wrap around,
+//! or with `checked_next()` and `checked_prev()`, which don't:
+//!
+//! ```
+//! # use kinded::Kinded;
+//! # #[derive(Kinded)]
+//! # enum Drink { Mate, Coffee(String), Tea { variety: String, caffeine: bool } }
+//! assert_eq!(DrinkKind::Tea.next(), DrinkKind::Mate);
+//! assert_eq!(DrinkKind::Tea.checked_next(), None);
+//! assert_eq!(DrinkKind::Tea.prev(), DrinkKind::Coffee);
+//! ```
+//!
+//! Kinds can be also looked up by their exact display name with `by_name()`, or selected with a predicate using `matching()`:
+//!
+//! ```
+//! # use kinded::Kinded;
+//! # #[derive(Kinded)]
+//! # enum Drink { Mate, Coffee(String), Tea { variety: String, caffeine: bool } }
+//! assert_eq!(DrinkKind::by_name("Tea"), Some(DrinkKind::Tea));
+//! assert_eq!(DrinkKind::by_name("Cola"), None);
+//!
+//! let kinds: Vec<_> = DrinkKind::matching(|kind| kind.arity() > 0).collect();
+//! assert_eq!(kinds, [DrinkKind::Coffee, DrinkKind::Tea]);
+//! ```
+//!
+//! A contiguous band of kinds can be iterated with `range()`, which accepts any kind of range:
+//!
+//! ```
+//! # use kinded::Kinded;
+//! # #[derive(Kinded)]
+//! # enum Drink { Mate, Coffee(String), Tea { variety: String, caffeine: bool } }
+//! let kinds: Vec<_> = DrinkKind::range(DrinkKind::Coffee..=DrinkKind::Tea).collect();
+//! assert_eq!(kinds, [DrinkKind::Coffee, DrinkKind::Tea]);
+//! ```
+//!
+//! ## Fields of a variant
+//!
+//! `arity()` returns the number of fields of the corresponding variant:
+//!
+//! ```
+//! # use kinded::Kinded;
+//! # #[derive(Kinded)]
+//! # enum Drink { Mate, Coffee(String), Tea { variety: String, caffeine: bool } }
+//! assert_eq!(DrinkKind::Mate.arity(), 0);
+//! assert_eq!(DrinkKind::Coffee.arity(), 1);
+//! assert_eq!(DrinkKind::Tea.arity(), 2);
+//! ```
+//!
+//! `fields_type()` tells whether the variant has named, unnamed or no fields:
+//!
+//! ```
+//! # use kinded::Kinded;
+//! # #[derive(Kinded)]
+//! # enum Drink { Mate, Coffee(String), Tea { variety: String, caffeine: bool } }
+//! use kinded::FieldsType;
+//!
+//! assert_eq!(DrinkKind::Mate.fields_type(), FieldsType::Unit);
+//! assert_eq!(DrinkKind::Coffee.fields_type(), FieldsType::Unnamed);
+//! assert_eq!(DrinkKind::Tea.fields_type(), FieldsType::Named);
+//! ```
+//!
+//! ## Convert a kind back
+//!
+//! When all the variants of the enum are unit variants, the enum and the kind type hold the same information,
+//! so `From<DrinkKind>` is implemented for the enum as well:
+//!
+//! ```
+//! use kinded::Kinded;
+//!
+//! #[derive(Kinded)]
+//! enum Direction {
+//!     Up,
+//!     Down,
+//! }
+//!
+//! let direction: Direction = DirectionKind::Up.into();
+//! assert_eq!(direction.kind(), DirectionKind::Up);
+//! ```
+//!
+//! With `try_into_original` attribute `TryFrom<DrinkKind>` is implemented for the enum even when variants carry data.
+//! The fields are constructed with `Default::default()`, so the conversion succeeds only for variants, whose fields all implement `Default`.
+//! Otherwise the kind is returned back as an error:
+//!
+//! ```
+//! use kinded::Kinded;
+//!
+//! struct Secret;
+//!
+//! #[derive(Kinded)]
+//! #[kinded(try_into_original)]
+//! enum Drink {
+//!     Mate,
+//!     Coffee(String),
+//!     Potion(Secret),
+//! }
+//!
+//! assert!(matches!(Drink::try_from(DrinkKind::Coffee), Ok(Drink::Coffee(name)) if name.is_empty()));
+//! assert!(matches!(Drink::try_from(DrinkKind::Potion), Err(DrinkKind::Potion)));
+//! ```
+//!
+//! Whether a field implements `Default` is decided on the bounds declared on the enum, not on the type it is instantiated with.
+//! A field whose type is a type parameter `T` adds a `T: Default` bound to the implementation, so `Slot<i32>` below converts,
+//! while `Slot<Secret>` has no `TryFrom<SlotKind>` at all. Other field types mentioning `T`, e.g. `Box<T>`, are constructed
+//! only if the enum declares the bounds they need.
+//!
+//! ```
+//! use kinded::Kinded;
+//!
+//! #[derive(Kinded, Debug, PartialEq)]
+//! #[kinded(try_into_original)]
+//! enum Slot<T> {
+//!     Empty,
+//!     Filled(T),
+//! }
+//!
+//! assert_eq!(Slot::<i32>::try_from(SlotKind::Filled), Ok(Slot::Filled(0)));
+//! ```
+//!
+//! ## Attributes
+//!
+//! The attributes can be combined in a single `#[kinded(..)]` or split across several of them, e.g. `#[kinded(kind = SimpleDrink)]` and `#[kinded(derive(Hash))]`.
+//!
+//! ### Custom kind type name
+//!
+//! By default the kind type name is generated by adding postfix `Kind` to the original enum name.
+//! This can be customized with `kind = ` attribute:
+//!
+//! ```
+//! use kinded::Kinded;
+//!
+//! #[derive(Kinded)]
+//! #[kinded(kind = SimpleDrink)]
+//! enum Drink {
+//!     Mate,
+//!     Coffee(String),
+//!     Tea { variety: String, caffeine: bool }
+//! }
+//!
+//! assert_eq!(Drink::Mate.kind(), SimpleDrink::Mate);
+//! ```
+//!
+//! Alternatively, the generated name can be adjusted with `prefix = ` and `suffix = ` attributes, e.g. to follow a naming convention across all the enums.
+//! If only `prefix` is given, the `Kind` postfix is omitted. `kind = ` takes precedence over both:
+//!
+//! ```
+//! use kinded::Kinded;
+//!
+//! #[derive(Kinded)]
+//! #[kinded(suffix = "Tag")]
+//! enum Drink {
+//!     Mate,
+//!     Coffee(String),
+//! }
+//!
+//! #[derive(Kinded)]
+//! #[kinded(prefix = "K")]
+//! enum Meal {
+//!     Breakfast,
+//!     Dinner,
+//! }
+//!
+//! assert_eq!(Drink::Mate.kind(), DrinkTag::Mate);
+//! assert_eq!(Meal::Dinner.kind(), KMeal::Dinner);
+//! ```
+//!
+//! ### Method name
+//!
+//! `kind()` may be a poor name in some domains. The inherent method can be renamed with `method = ...` attribute,
+//! while `kind()` of the `Kinded` trait stays available:
+//!
+//! ```
+//! use kinded::Kinded;
+//!
+//! #[derive(Kinded)]
+//! #[kinded(method = category)]
+//! enum Drink {
+//!     Mate,
+//!     Coffee(String),
+//! }
+//!
+//! assert_eq!(Drink::Mate.category(), DrinkKind::Mate);
+//! assert_eq!(Drink::Mate.kind(), DrinkKind::Mate);
+//! ```
+//!
+//! ### Existing kind type
+//!
+//! If the kind type is already written by hand, e.g. because it's shared by several enums, `external` attribute maps to it
+//! instead of generating a new one. Every variant is mapped to the variant with the same name in the type given with `kind = ...`,
+//! and it's a compile error if there is no such variant. The type must implement the traits required by `Kinded::Kind`:
+//!
+//! ```
+//! use kinded::{Kind, Kinded};
+//!
+//! #[derive(Debug, Clone, PartialEq, Eq)]
+//! enum BeverageKind {
+//!     Mate,
+//!     Coffee,
+//!     Water,
+//! }
+//!
+//! impl Kind for BeverageKind {
+//!     fn all() -> &'static [Self] {
+//!         &[Self::Mate, Self::Coffee, Self::Water]
+//!     }
+//! }
+//!
+//! #[derive(Kinded)]
+//! #[kinded(kind = BeverageKind, external)]
+//! enum Drink {
+//!     Mate,
+//!     Coffee(String),
+//! }
+//!
+//! assert_eq!(Drink::Coffee("Espresso".to_owned()).kind(), BeverageKind::Coffee);
+//! ```
+//!
+//! Only `kind()`, `is_kind()`, `into_kind()` and the `Kinded` trait are generated then,
+//! so the attributes customizing the kind type can not be combined with `external`.
+//!
+//! ### Kind variant names
+//!
+//! To avoid ambiguity when the variants of both the enum and its kind are imported with a glob, the kind variants can be prefixed with `variant_prefix = "..."`.
+//! It changes only the identifiers, the kind variants are still displayed and parsed by the original names:
+//!
+//! ```
+//! use kinded::Kinded;
+//!
+//! #[derive(Kinded)]
+//! #[kinded(variant_prefix = "K")]
+//! enum Drink {
+//!     Mate,
+//!     Coffee(String),
+//! }
+//!
+//! use Drink::*;
+//! use DrinkKind::*;
+//!
+//! assert_eq!(Mate.kind(), KMate);
+//! assert_eq!(KMate.to_string(), "Mate");
+//! ```
+//!
+//! ### Order of all()
+//!
+//! `all()` and `ALL` list the kinds in the declaration order. With `all_order = "name"` they are sorted by the display names instead,
+//! e.g. for menus. The order is computed by the macro, so there is no runtime cost.
+//! Everything iterating over all the kinds, like `all_named()`, follows it, while the indexes, `NAMES`, `next()` and `range()`
+//! stay in the declaration order:
+//!
+//! ```
+//! use kinded::Kinded;
+//!
+//! #[derive(Kinded)]
+//! #[kinded(all_order = "name")]
+//! enum Drink {
+//!     Tea,
+//!     Mate,
+//!     Coffee(String),
+//! }
+//!
+//! assert_eq!(DrinkKind::all(), [DrinkKind::Coffee, DrinkKind::Mate, DrinkKind::Tea]);
+//! assert_eq!(DrinkKind::Tea.to_index(), 0);
+//! ```
+//!
+//! ### Representation
+//!
+//! The representation of the kind type can be specified with `repr = ` attribute, so the kind can be safely cast to an integer:
+//!
+//! ```
+//! use kinded::Kinded;
+//!
+//! #[derive(Kinded)]
+//! #[kinded(repr = u8)]
+//! enum Drink {
+//!     Mate,
+//!     Coffee(String),
+//!     Tea { variety: String, caffeine: bool }
+//! }
+//!
+//! assert_eq!(DrinkKind::Tea as u8, 2);
+//! ```
+//!
+//! Explicit discriminants of the enum are preserved on the kind type, together with the integer representation of the enum,
+//! unless `repr = ` attribute is given:
+//!
+//! ```
+//! use kinded::Kinded;
+//!
+//! #[derive(Kinded)]
+//! #[repr(u16)]
+//! enum Code {
+//!     Ok = 200,
+//!     NotFound = 404,
+//! }
+//!
+//! assert_eq!(CodeKind::NotFound as u16, 404);
+//! ```
+//!
+//! Note, that Rust allows discriminants on variants with fields only when the enum has an explicit `#[repr(..)]`.
+//!
+//! ### Fast kind
+//!
+//! For enums with many variants, `kind()` is a big `match`. When the enum has an integer representation, e.g. `#[repr(u8)]`,
+//! `fast_kind` attribute makes `kind()` read the tag of the enum and look the kind up in a table instead.
+//! It's accepted only when it's sound: there must be no explicit discriminants and no skipped variants.
+//! Note, that the generated `kind()` uses `unsafe` code then:
+//!
+//! ```
+//! use kinded::Kinded;
+//!
+//! #[derive(Kinded)]
+//! #[kinded(fast_kind)]
+//! #[repr(u8)]
+//! enum Drink {
+//!     Mate,
+//!     Coffee(String),
+//! }
+//!
+//! assert_eq!(Drink::Coffee("Espresso".to_owned()).kind(), DrinkKind::Coffee);
+//! ```
+//!
+//! ### Stable ids
+//!
+//! For persistence, the kinds can get numeric ids that don't depend on the declaration order, with `#[kinded(id = ...)]` on the variants.
+//! Once an id is given to a variant, it must be given to all the kind variants, and the ids must be unique:
+//!
+//! ```
+//! use kinded::Kinded;
+//!
+//! #[derive(Kinded)]
+//! enum Drink {
+//!     #[kinded(id = 2)]
+//!     Coffee(String),
+//!     #[kinded(id = 1)]
+//!     Mate,
+//! }
+//!
+//! assert_eq!(DrinkKind::Mate.stable_id(), 1);
+//! assert_eq!(DrinkKind::from_stable_id(2), Some(DrinkKind::Coffee));
+//! assert_eq!(DrinkKind::from_stable_id(3), None);
+//! ```
+//!
+//! ### Default
+//!
+//! The `Default` trait can be implemented for the kind type with `default = ` attribute:
+//!
+//! ```
+//! use kinded::Kinded;
+//!
+//! #[derive(Kinded)]
+//! #[kinded(default = Mate)]
+//! enum Drink {
+//!     Mate,
+//!     Coffee(String),
+//! }
+//!
+//! assert_eq!(DrinkKind::default(), DrinkKind::Mate);
+//! ```
+//!
+//! Alternatively, the default variant can be marked right in the enum, the same way as `#[default]` works for `#[derive(Default)]`:
+//!
+//! ```
+//! use kinded::Kinded;
+//!
+//! #[derive(Kinded)]
+//! enum Drink {
+//!     Mate,
+//!     #[kinded(default)]
+//!     Coffee(String),
+//! }
+//!
+//! assert_eq!(DrinkKind::default(), DrinkKind::Coffee);
+//! ```
+//!
+//! ### Non-exhaustive
+//!
+//! If the enum is marked with `#[non_exhaustive]`, the kind type is marked with it as well.
+//! The kind type can also be made non-exhaustive on its own with `#[kinded(non_exhaustive)]` attribute.
+//!
+//! ### Must use
+//!
+//! With `#[kinded(must_use)]` attribute the kind type is marked with `#[must_use]`,
+//! so the compiler warns when a kind returned by `kind()` or any other function is accidentally ignored.
+//!
+//! ### Skip variants
+//!
+//! A variant can be excluded from the kind type with `#[kinded(skip)]` attribute.
+//! Calling `kind()` on a skipped variant panics.
+//!
+//! ```
+//! use kinded::Kinded;
+//!
+//! #[derive(Kinded)]
+//! enum Drink {
+//!     Mate,
+//!     #[kinded(skip)]
+//!     Poison,
+//!     Tea,
+//! }
+//!
+//! assert_eq!(DrinkKind::all(), [DrinkKind::Mate, DrinkKind::Tea]);
+//! ```
+//!
+//! ### Forward attributes
+//!
+//! Arbitrary attributes can be put on the kind type with `attr(..)` attribute.
+//! It's handy to configure other derive macros, that are applied to the kind type:
+//!
+//! ```ignore
+//! use kinded::Kinded;
+//!
+//! #[derive(Kinded)]
+//! #[kinded(derive(serde::Serialize), attr(serde(rename_all = "kebab-case")))]
+//! enum Drink {
+//!     VeryHotBlackTea,
+//!     Milk { fat: f64 },
+//! }
+//! ```
+//!
+//! Note, that the attributes are applied only to the kind type, see [Traits of the main enum](#traits-of-the-main-enum).
+//!
+//! Doc comments and other `#[doc(..)]` attributes of the variants are forwarded to the kind variants.
+//! So a `#[doc(hidden)]` variant stays hidden in the kind type as well, together with its `is_<variant>()` predicate
+//! and `<VARIANT>_INDEX` constant.
+//!
+//! ### Visibility
+//!
+//! By default the kind type inherits the visibility of the enum.
+//! This can be customized with `vis = ` attribute:
+//!
+//! ```
+//! mod drinks {
+//!     use kinded::Kinded;
+//!
+//!     #[derive(Kinded)]
+//!     #[kinded(vis = pub)]
+//!     enum Drink {
+//!         Mate,
+//!         Coffee(String),
+//!     }
+//! }
+//!
+//! let mate = drinks::DrinkKind::Mate;
+//! ```
+//!
+//! ### Module
+//!
+//! To keep the namespace clean, the kind type and its implementations can be generated in a separate module with `module = ` attribute.
+//! The module gets the visibility the kind type would have, and it imports everything from the parent module.
+//!
+//! ```
+//! mod drinks {
+//!     use kinded::Kinded;
+//!
+//!     #[derive(Kinded)]
+//!     #[kinded(module = kinds)]
+//!     pub enum Drink {
+//!         Mate,
+//!         Coffee(String),
+//!     }
+//! }
+//!
+//! let mate = drinks::kinds::DrinkKind::Mate;
+//! ```
+//!
+//! Since the module is a sibling of the enum, the enum can't be declared inside a function body, and each enum needs its own module name.
+//!
+//! ### Derive traits
 //!
-//! ## Kinded trait
+//! By default the kind type implements the following traits: `Debug`, `Clone`, `Copy`, `PartialEq`, `Eq`, `Hash`, `Display`, `AsRef<str>`, `PartialEq<str>`, `FromStr`, `From<T>`, `From<&T>`, `TryFrom<&str>`, `TryFrom<String>`.
+//! The kind type can also be converted into `&'static str` and `String`, using the display name.
 //!
-//! The library provides `Kinded` trait:
+//! Extra traits can be derived with `derive(..)` attribute:
 //!
-//! ```rs
-//! pub trait Kinded {
-//!     type Kind: PartialEq + Eq + Debug + Clone + Copy;
+//! ```
+//! use kinded::Kinded;
+//! use std::collections::BTreeSet;
 //!
-//!     fn kind(&self) -> Self::Kind;
+//! #[derive(Kinded)]
+//! #[kinded(derive(PartialOrd, Ord))]
+//! enum Drink {
+//!     Mate,
+//!     Coffee(String),
+//!     Tea { variety: String, caffeine: bool }
 //! }
+//!
+//! let mut drink_kinds = BTreeSet::new();
+//! drink_kinds.insert(DrinkKind::Mate);
 //! ```
 //!
-//! From the example above, the derived implementation of `Kinded` for `Drink` resembles the following:
+//! Here the kinds are ordered by their position in the enum definition, the same way as `to_index()` does.
+//! Note, that derived `PartialOrd` and `Ord` compare the discriminants. When the enum has [explicit discriminants](#representation),
+//! they are preserved on the kind type, so the kinds are ordered by the discriminant values instead,
+//! e.g. `Ok = 200` comes before `NotFound = 404`, even if `NotFound` is declared first and has index 0.
 //!
-//! ```ignore
-//! impl Kinded for Drink {
-//!     type Kind = DrinkKind;
+//! `Hash` hashes a single `u64`: the declaration index of the kind, or its stable id if the kinds have [stable ids](#stable-ids).
+//! Unlike the derived implementation, it doesn't depend on the discriminants and is the same on all platforms.
+//!
+//! Default traits which the generated code doesn't rely on (currently only `Hash`) can be removed with `no_derive(..)` attribute,
+//! e.g. to implement them manually:
 //!
-//!     fn kind(&self) -> DrinkKind { /* implementation */ }
-//! }
 //! ```
+//! use kinded::Kinded;
 //!
-//! The `Kinded` trait allows to build abstract functions that can be used with different enum types.
+//! #[derive(Kinded)]
+//! #[kinded(no_derive(Hash))]
+//! enum Drink {
+//!     Mate,
+//!     Coffee(String),
+//! }
 //!
-//! ## Get all kind variants
+//! impl std::hash::Hash for DrinkKind {
+//!     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+//!         self.as_str().hash(state);
+//!     }
+//! }
+//! ```
 //!
-//! The kind type gets implementation of `::all()` associated function, which returns a vector with all kind variants:
+//! `Copy` can be removed as well, e.g. to make every copy of the kind an explicit `.clone()`.
+//! The `Kinded` and `Kind` traits, `all()`, `iter()`, `as_str()`, `Display`, `FromStr` and the other conversions
+//! don't need to copy the kind and are still generated. The methods taking the kind by value, e.g. `to_index()`,
+//! `from_index()`, `next()` or `is_<variant>()`, are not, so `serde_repr`, `histogram`, `set`, `fast_kind` and `id`,
+//! which rely on them, are rejected:
 //!
 //! ```
 //! use kinded::Kinded;
 //!
 //! #[derive(Kinded)]
+//! #[kinded(no_derive(Copy))]
 //! enum Drink {
 //!     Mate,
 //!     Coffee(String),
-//!     Tea { variety: String, caffeine: bool }
 //! }
 //!
-//! assert_eq!(DrinkKind::all(), [DrinkKind::Mate, DrinkKind::Coffee, DrinkKind::Tea]);
+//! let kind = Drink::Coffee("Espresso".to_owned()).kind();
+//! assert_eq!(kind.clone(), DrinkKind::Coffee);
+//! assert_eq!(kind.to_string(), "Coffee");
+//! assert_eq!(DrinkKind::all(), [DrinkKind::Mate, DrinkKind::Coffee]);
 //! ```
 //!
-//! ## Attributes
+//! ### Traits of the main enum
 //!
-//! ### Custom kind type name
+//! A derive macro can only add new items next to the enum, it can't change the enum itself.
+//! So the derives of the main enum can't be set with `#[kinded(..)]` and must be written on the enum directly.
+//! The traits that can be derived from the kind are implemented for the main enum on request instead:
 //!
-//! By default the kind type name is generated by adding postfix `Kind` to the original enum name.
-//! This can be customized with `kind = ` attribute:
+//! * `display_original` implements `Display`, delegating to the kind.
+//! * `eq_original` implements `PartialEq<DrinkKind>` for the enum and `PartialEq<Drink>` for the kind.
+//! * `try_into_original` implements `TryFrom<DrinkKind>`, building the variant with default fields.
 //!
 //! ```
 //! use kinded::Kinded;
 //!
-//! #[derive(Kinded)]
-//! #[kinded(kind = SimpleDrink)]
+//! #[derive(Kinded, Debug, Clone)]
+//! #[kinded(display = "snake_case", display_original, eq_original, try_into_original)]
 //! enum Drink {
 //!     Mate,
 //!     Coffee(String),
-//!     Tea { variety: String, caffeine: bool }
 //! }
 //!
-//! assert_eq!(Drink::Mate.kind(), SimpleDrink::Mate);
+//! let coffee = Drink::try_from(DrinkKind::Coffee).unwrap();
+//! assert!(coffee == DrinkKind::Coffee);
+//! assert_eq!(coffee.to_string(), "coffee");
 //! ```
 //!
-//! ### Derive traits
-//!
-//! By default the kind type implements the following traits: `Debug`, `Clone`, `Copy`, `PartialEq`, `Eq`, `From<T>`, `From<&T>`.
+//! ### Compare with the kind
 //!
-//! Extra traits can be derived with `derive(..)` attribute:
+//! With `eq_original` attribute the main enum and the kind type can be compared directly in both directions:
 //!
 //! ```
 //! use kinded::Kinded;
-//! use std::collections::HashSet;
 //!
 //! #[derive(Kinded)]
-//! #[kinded(derive(Hash))]
+//! #[kinded(eq_original)]
 //! enum Drink {
 //!     Mate,
 //!     Coffee(String),
-//!     Tea { variety: String, caffeine: bool }
 //! }
 //!
-//! let mut drink_kinds = HashSet::new();
-//! drink_kinds.insert(DrinkKind::Mate);
+//! let coffee = Drink::Coffee("Espresso".to_owned());
+//! assert!(coffee == DrinkKind::Coffee);
+//! assert!(DrinkKind::Mate != coffee);
 //! ```
 //!
 //! ### Customize Display trait
@@ -139,6 +910,188 @@
 //! assert_eq!(tea.to_string(), "very_hot_black_tea");
 //! ```
 //!
+//! The cases separating the words, i.e. all but `"camelCase"`, `"PascalCase"`, `"lowercase"` and `"UPPERCASE"`,
+//! can join them with a custom separator given with `separator = "..."`:
+//!
+//! ```
+//! use kinded::Kinded;
+//!
+//! #[derive(Kinded)]
+//! #[kinded(display = "snake_case", separator = ".")]
+//! enum Drink {
+//!     VeryHotBlackTea,
+//!     Milk { fat: f64 },
+//! }
+//!
+//! assert_eq!(DrinkKind::VeryHotBlackTea.to_string(), "very.hot.black.tea");
+//! ```
+//!
+//! The same string can be obtained without allocation with `as_str()` method or through `AsRef<str>` trait:
+//!
+//! ```
+//! use kinded::Kinded;
+//!
+//! #[derive(Kinded)]
+//! #[kinded(display = "snake_case")]
+//! enum Drink {
+//!     VeryHotBlackTea,
+//!     Milk { fat: f64 },
+//! }
+//!
+//! assert_eq!(DrinkKind::VeryHotBlackTea.as_str(), "very_hot_black_tea");
+//! ```
+//!
+//! A kind can also be compared with a string slice directly. The comparison is exact, so unlike parsing it doesn't accept other casings. To look a kind up by name in a `HashMap<DrinkKind, _>`, parse the name into a kind first:
+//!
+//! ```
+//! # use kinded::Kinded;
+//! # #[derive(Kinded)]
+//! # #[kinded(display = "snake_case")]
+//! # enum Drink { VeryHotBlackTea }
+//! assert!(DrinkKind::VeryHotBlackTea == *"very_hot_black_tea");
+//! assert!(DrinkKind::VeryHotBlackTea != *"VeryHotBlackTea");
+//! ```
+//!
+//! A single variant can be displayed differently with `#[kinded(rename = "...")]` attribute, which takes precedence over `display`:
+//!
+//! ```
+//! use kinded::Kinded;
+//!
+//! #[derive(Kinded)]
+//! #[kinded(display = "snake_case")]
+//! enum Protocol {
+//!     #[kinded(rename = "h2")]
+//!     Http2,
+//!     WebSocket,
+//! }
+//!
+//! assert_eq!(ProtocolKind::Http2.to_string(), "h2");
+//! assert_eq!(ProtocolKind::WebSocket.to_string(), "web_socket");
+//! ```
+//!
+//! Only the case of a variant can be overridden with `#[kinded(display = "...")]` on that variant. `rename` still wins over it:
+//!
+//! ```
+//! use kinded::Kinded;
+//!
+//! #[derive(Kinded)]
+//! #[kinded(display = "snake_case")]
+//! enum Level {
+//!     #[kinded(display = "SCREAMING_SNAKE_CASE")]
+//!     VeryHigh,
+//!     Low,
+//! }
+//!
+//! assert_eq!(LevelKind::VeryHigh.to_string(), "VERY_HIGH");
+//! assert_eq!(LevelKind::Low.to_string(), "low");
+//! ```
+//!
+//! If the enum is serialized with serde, `display = "serde"` makes the kind display the same names as serde does.
+//! It mirrors `#[serde(rename_all = "...")]` of the enum and `#[serde(rename = "...")]` of the variants.
+//! The words are split the way serde does it, which differs from the other display cases for acronyms and digits,
+//! e.g. `Ipv4Addr` is displayed as `ipv4_addr` with `rename_all = "snake_case"`, but as `ipv_4_addr` with `display = "snake_case"`:
+//!
+//! ```ignore
+//! use kinded::Kinded;
+//! use serde::Serialize;
+//!
+//! #[derive(Kinded, Serialize)]
+//! #[serde(tag = "type", rename_all = "kebab-case")]
+//! #[kinded(display = "serde")]
+//! enum Event {
+//!     UserCreated { id: u64 },
+//!     #[serde(rename = "removed")]
+//!     UserDeleted { id: u64 },
+//! }
+//!
+//! assert_eq!(EventKind::UserCreated.to_string(), "user-created");
+//! assert_eq!(EventKind::UserDeleted.to_string(), "removed");
+//! ```
+//!
+//! With `display_original` attribute `Display` trait is also implemented for the main enum, delegating to the kind.
+//! It's handy for error enums, when only the variant name is needed:
+//!
+//! ```
+//! use kinded::Kinded;
+//!
+//! #[derive(Kinded)]
+//! #[kinded(display = "snake_case", display_original)]
+//! enum Drink {
+//!     Mate,
+//!     HotCoffee(String),
+//! }
+//!
+//! let coffee = Drink::HotCoffee("Espresso".to_owned());
+//! assert_eq!(coffee.to_string(), "hot_coffee");
+//! ```
+//!
+//! ### Histogram
+//!
+//! With `histogram` attribute a `DrinkKindHistogram` type is generated along with the kind type.
+//! It counts the kinds in an array indexed by `to_index()`, so unlike `count_by_kind()` it needs neither hashing nor allocation:
+//!
+//! ```
+//! use kinded::Kinded;
+//!
+//! #[derive(Kinded)]
+//! #[kinded(histogram)]
+//! enum Drink {
+//!     Mate,
+//!     Coffee(String),
+//! }
+//!
+//! let drinks = vec![Drink::Mate, Drink::Coffee("Espresso".to_owned()), Drink::Mate];
+//! let histogram: DrinkKindHistogram = drinks.iter().map(Drink::kind).collect();
+//! assert_eq!(histogram.get(DrinkKind::Mate), 2);
+//! assert_eq!(histogram.total(), 3);
+//! ```
+//!
+//! ### Set
+//!
+//! With `set` attribute a `DrinkKindSet` type is generated along with the kind type.
+//! It keeps every kind as a bit of `u64` at its `to_index()`, so it's a cheap `Copy` alternative to `HashSet<DrinkKind>`
+//! for enums with at most 64 kinds:
+//!
+//! ```
+//! use kinded::Kinded;
+//!
+//! #[derive(Kinded)]
+//! #[kinded(set)]
+//! enum Drink {
+//!     Mate,
+//!     Coffee(String),
+//!     Tea { variety: String, caffeine: bool },
+//! }
+//!
+//! let hot: DrinkKindSet = [DrinkKind::Coffee, DrinkKind::Tea].into_iter().collect();
+//! let mut allowed = DrinkKindSet::new();
+//! allowed.insert(DrinkKind::Mate);
+//! allowed.insert(DrinkKind::Tea);
+//!
+//! assert!(allowed.contains(DrinkKind::Tea));
+//! assert_eq!(hot.intersection(allowed).iter().collect::<Vec<_>>(), [DrinkKind::Tea]);
+//! assert_eq!(hot.union(allowed).len(), 3);
+//! ```
+//!
+//! ### Error trait
+//!
+//! With `error` attribute the kind type implements `Error` trait, e.g. to return the category of an error enum as `Box<dyn Error>`.
+//! It requires `std` feature, which is enabled by default:
+//!
+//! ```
+//! use kinded::Kinded;
+//!
+//! #[derive(Kinded)]
+//! #[kinded(error, display = "snake_case")]
+//! enum FetchError {
+//!     Timeout,
+//!     NotFound(String),
+//! }
+//!
+//! let error: Box<dyn std::error::Error> = Box::new(FetchErrorKind::Timeout);
+//! assert_eq!(error.to_string(), "timeout");
+//! ```
+//!
 //! ### FromStr trait
 //!
 //! The kind type implements `FromStr` trait. The implementation tries it's best to parse, checking all the possible cases mentioned above.
@@ -164,7 +1117,171 @@
 //! );
 //! ```
 //!
-//! The possible values are `"snake_case"`, `"camelCase"`, `"PascalCase"`, `"SCREAMING_SNAKE_CASE"`, `"kebab-case"`, `"SCREAMING-KEBAB-CASE"`, `"Title Case"`, `"lowercase"`, `"UPPERCASE"`.
+//! The possible values are `"snake_case"`, `"camelCase"`, `"PascalCase"`, `"SCREAMING_SNAKE_CASE"`, `"kebab-case"`, `"SCREAMING-KEBAB-CASE"`, `"Title Case"`, `"Train-Case"`, `"lowercase"` (or `"flatcase"`), `"UPPERCASE"`.
+//! The values without `-` and spaces can be given without quotes as well, e.g. `display = snake_case`.
+//!
+//! On failure the error lists the display names of all the kind variants:
+//!
+//! ```
+//! # use kinded::Kinded;
+//! # #[derive(Kinded)]
+//! # #[kinded(display = "snake_case")]
+//! # enum Drink { VeryHotBlackTea, Milk { fat: f64 } }
+//! let error = "tea".parse::<DrinkKind>().unwrap_err();
+//! assert_eq!(error.valid_names(), ["very_hot_black_tea", "milk"]);
+//! assert_eq!(
+//!     error.to_string(),
+//!     r#"Failed to parse "tea" as DrinkKind (expected one of: very_hot_black_tea, milk)"#
+//! );
+//! ```
+//!
+//! With `parse_case_insensitive` attribute any case variation of the displayed name, including non-ASCII letters, is accepted as well:
+//!
+//! ```
+//! use kinded::Kinded;
+//!
+//! #[derive(Kinded)]
+//! #[kinded(display = "snake_case", parse_case_insensitive)]
+//! enum Drink {
+//!     VeryHotBlackTea,
+//!     Milk { fat: f64 },
+//! }
+//!
+//! assert_eq!(
+//!     "Very_Hot_Black_Tea".parse::<DrinkKind>().unwrap(),
+//!     DrinkKind::VeryHotBlackTea
+//! );
+//! ```
+//!
+//! The displayed name is always tried first, so parsing round-trips with `Display`.
+//! The generated implementation can be skipped with `no_from_str` attribute, in case you want to provide your own:
+//!
+//! ```
+//! use kinded::Kinded;
+//!
+//! #[derive(Kinded)]
+//! #[kinded(no_from_str)]
+//! enum Drink {
+//!     Mate,
+//!     Tea,
+//! }
+//!
+//! impl std::str::FromStr for DrinkKind {
+//!     type Err = ();
+//!
+//!     fn from_str(s: &str) -> Result<Self, Self::Err> {
+//!         match s {
+//!             "yerba" => Ok(DrinkKind::Mate),
+//!             _ => Err(()),
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! ### Debug
+//!
+//! To see the code generated by the macro, use `#[kinded(debug)]` attribute.
+//! The code is printed to stderr during the compilation.
+//!
+//! ## Feature flags
+//!
+//! ### serde
+//!
+//! With `serde` feature enabled, the kind type can implement `Serialize` and `Deserialize` traits with `#[kinded(serde)]` attribute.
+//! The kind is serialized as its display name, and deserialized using the `FromStr` implementation:
+//!
+//! ```ignore
+//! use kinded::Kinded;
+//!
+//! #[derive(Kinded)]
+//! #[kinded(display = "snake_case", serde)]
+//! enum Drink {
+//!     VeryHotBlackTea,
+//!     Milk { fat: f64 },
+//! }
+//!
+//! let json = serde_json::to_string(&DrinkKind::VeryHotBlackTea).unwrap();
+//! assert_eq!(json, r#""very_hot_black_tea""#);
+//! ```
+//!
+//! For a compact form `#[kinded(serde_repr)]` serializes the kind as its index in the declaration order, a `u32`,
+//! similar to the `serde_repr` crate. It can't be combined with `serde` attribute:
+//!
+//! ```ignore
+//! use kinded::Kinded;
+//!
+//! #[derive(Kinded)]
+//! #[kinded(serde_repr)]
+//! enum Drink {
+//!     Mate,
+//!     Coffee(String),
+//! }
+//!
+//! assert_eq!(serde_json::to_string(&DrinkKind::Coffee).unwrap(), "1");
+//! let kind: DrinkKind = serde_json::from_str("0").unwrap();
+//! assert_eq!(kind, DrinkKind::Mate);
+//! ```
+//!
+//! ### std
+//!
+//! The `std` feature is enabled by default.
+//! The crate and the generated code rely only on `core` and `alloc`, so `kinded` can be used in `no_std` environment
+//! by disabling the default features:
+//!
+//! ```toml
+//! kinded = { version = "0.3", default-features = false }
+//! ```
+//!
+//! `Error` trait is implemented for `ParseKindError` and `#[kinded(error)]` is available only with `std` feature.
+//!
+//! ### clap
+//!
+//! With `clap` feature enabled, the kind type can implement [clap](https://docs.rs/clap)'s `ValueEnum` trait with `#[kinded(clap)]` attribute,
+//! so it can be used as a command line argument. The display names are used as the possible values:
+//!
+//! ```ignore
+//! use clap::Parser;
+//! use kinded::Kinded;
+//!
+//! #[derive(Kinded)]
+//! #[kinded(display = "snake_case", clap)]
+//! enum Drink {
+//!     VeryHotBlackTea,
+//!     Milk { fat: f64 },
+//! }
+//!
+//! #[derive(Parser)]
+//! struct Cli {
+//!     #[arg(long, value_enum)]
+//!     drink: DrinkKind,
+//! }
+//!
+//! let cli = Cli::parse_from(["cli", "--drink", "very_hot_black_tea"]);
+//! assert_eq!(cli.drink, DrinkKind::VeryHotBlackTea);
+//! ```
+//!
+//! ### strum
+//!
+//! With `strum` feature enabled, `#[kinded(strum)]` attribute makes the kind type compatible with generic code built on top of [strum](https://docs.rs/strum).
+//! It implements `EnumCount`, `VariantNames` and `IntoEnumIterator` traits.
+//! The conversions, that `IntoStaticStr` and `EnumString` would generate, are always implemented by the kind type. The display names are used for all of them:
+//!
+//! ```ignore
+//! use kinded::Kinded;
+//! use strum::{IntoEnumIterator, VariantNames};
+//!
+//! #[derive(Kinded)]
+//! #[kinded(display = "snake_case", strum)]
+//! enum Drink {
+//!     VeryHotBlackTea,
+//!     Milk { fat: f64 },
+//! }
+//!
+//! let name: &'static str = DrinkKind::VeryHotBlackTea.into();
+//! assert_eq!(name, "very_hot_black_tea");
+//! assert_eq!(DrinkKind::VARIANTS, ["very_hot_black_tea", "milk"]);
+//! assert_eq!(DrinkKind::iter().count(), 2);
+//! ```
 //!
 //! ## A note about the war in Ukraine 🇺🇦
 //!
@@ -189,9 +1306,44 @@
 
 #![no_std]
 
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "std")]
+mod collect;
+mod default_probe;
 mod errors;
+mod fields_type;
+#[cfg(feature = "serde")]
+mod serde_support;
 mod traits;
 
+#[cfg(feature = "std")]
+pub use collect::{count_by_kind, group_by_kind};
 pub use errors::ParseKindError;
+pub use fields_type::FieldsType;
 pub use kinded_macros::Kinded;
 pub use traits::{Kind, Kinded};
+
+/// Not a public API. Used by the code generated with `kinded` macro.
+#[doc(hidden)]
+pub mod __private {
+    pub use alloc::string::String;
+
+    pub use crate::default_probe::{DefaultProbe, ProbeDefault, ProbeNoDefault};
+
+    #[cfg(feature = "std")]
+    pub use std::error::Error;
+
+    #[cfg(feature = "serde")]
+    pub use crate::serde_support::{deserialize_kind, deserialize_kind_index};
+    #[cfg(feature = "serde")]
+    pub use ::serde;
+
+    #[cfg(feature = "clap")]
+    pub use ::clap;
+
+    #[cfg(feature = "strum")]
+    pub use ::strum;
+}
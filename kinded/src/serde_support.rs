@@ -0,0 +1,58 @@
+use crate::ParseKindError;
+use ::core::{fmt, marker::PhantomData, str::FromStr};
+use ::serde::de::{Deserialize, Deserializer, Error, Expected, Unexpected, Visitor};
+
+/// This function is used by `kinded` macro to implement Deserialize trait for kind types
+/// and is not recommend for a direct usage by users.
+pub fn deserialize_kind<'de, D, KindType>(deserializer: D) -> Result<KindType, D::Error>
+where
+    D: Deserializer<'de>,
+    KindType: FromStr<Err = ParseKindError>,
+{
+    deserializer.deserialize_str(KindVisitor(PhantomData))
+}
+
+struct KindVisitor<KindType>(PhantomData<KindType>);
+
+impl<'de, KindType> Visitor<'de> for KindVisitor<KindType>
+where
+    KindType: FromStr<Err = ParseKindError>,
+{
+    type Value = KindType;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a string with a kind name")
+    }
+
+    fn visit_str<E: Error>(self, value: &str) -> Result<KindType, E> {
+        value.parse().map_err(E::custom)
+    }
+}
+
+/// This function is used by `kinded` macro to implement Deserialize trait for kind types
+/// with `serde_repr` attribute and is not recommend for a direct usage by users.
+/// `index_to_kind` lists the kinds in the declaration order.
+pub fn deserialize_kind_index<'de, D, KindType>(
+    deserializer: D,
+    index_to_kind: &[KindType],
+) -> Result<KindType, D::Error>
+where
+    D: Deserializer<'de>,
+    KindType: Clone,
+{
+    let index = u32::deserialize(deserializer)?;
+    index_to_kind.get(index as usize).cloned().ok_or_else(|| {
+        D::Error::invalid_value(
+            Unexpected::Unsigned(index.into()),
+            &KindIndex(index_to_kind.len()),
+        )
+    })
+}
+
+struct KindIndex(usize);
+
+impl Expected for KindIndex {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a kind index less than {}", self.0)
+    }
+}
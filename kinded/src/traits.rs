@@ -3,13 +3,40 @@ use ::core::fmt::Debug;
 /// A trait that can be implemented by a main enum type.
 /// Typically should be derived with `#[derive(kinded::Kinded)]`.
 pub trait Kinded {
-    type Kind: PartialEq + Eq + Debug + Clone + Copy + Kind;
+    /// The derived kind types are `Copy`, but it's not required,
+    /// so a manual implementation can point to a larger hand-written type.
+    type Kind: PartialEq + Eq + Debug + Clone + Kind;
 
     /// Get a kind variant without data.
     fn kind(&self) -> Self::Kind;
+
+    /// Get the display name of the kind variant.
+    fn kind_name(&self) -> &'static str
+    where
+        Self::Kind: Into<&'static str>,
+    {
+        self.kind().into()
+    }
+
+    /// Return a slice with all possible kind variants, without naming the kind type.
+    fn all_kinds() -> &'static [Self::Kind]
+    where
+        Self: Sized,
+    {
+        <Self::Kind as Kind>::all()
+    }
+}
+
+/// References are kinded as well, so generic code can take values by reference.
+impl<T: Kinded + ?Sized> Kinded for &T {
+    type Kind = T::Kind;
+
+    fn kind(&self) -> Self::Kind {
+        (**self).kind()
+    }
 }
 
-pub trait Kind: PartialEq + Eq + Debug + Clone + Copy {
+pub trait Kind: PartialEq + Eq + Debug + Clone + 'static {
     /// Return a slice with all possible kind variants.
     fn all() -> &'static [Self];
 }
@@ -0,0 +1,85 @@
+use crate::models::Meta;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Generate a histogram type counting the kinds in an array indexed by `to_index()`,
+/// as an alternative to `HashMap<DrinkKind, usize>` without hashing.
+pub fn gen_histogram(meta: &Meta) -> TokenStream {
+    if !meta.kinded_attrs.histogram {
+        return quote!();
+    }
+
+    let vis = meta.kind_vis();
+    let kind_name = meta.kind_name();
+    let histogram_name = meta.histogram_name();
+    let doc = format!("Number of occurrences of every [`{kind_name}`].");
+
+    quote!(
+        #[doc = #doc]                                                          // /// Number of occurrences of every [`DrinkKind`].
+        #[derive(                                                              // #[derive(Debug, Clone, PartialEq, Eq)]
+            ::core::fmt::Debug,
+            ::core::clone::Clone,
+            ::core::cmp::PartialEq,
+            ::core::cmp::Eq,
+        )]
+        #[allow(dead_code)]
+        #vis struct #histogram_name {                                          // pub struct DrinkKindHistogram {
+            counts: [usize; #kind_name::COUNT],                                //     counts: [usize; DrinkKind::COUNT],
+        }                                                                      // }
+
+        #[allow(dead_code)]
+        impl #histogram_name {                                                 // impl DrinkKindHistogram {
+            /// Creates a histogram with zero occurrences of every kind.
+            pub const fn new() -> Self {                                       //     pub const fn new() -> Self {
+                Self {                                                         //         Self {
+                    counts: [0; #kind_name::COUNT],                            //             counts: [0; DrinkKind::COUNT],
+                }                                                              //         }
+            }                                                                  //     }
+
+            /// Counts one more occurrence of the kind.
+            pub fn increment(&mut self, kind: #kind_name) {                    //     pub fn increment(&mut self, kind: DrinkKind) {
+                self.counts[kind.to_index()] += 1;                             //         self.counts[kind.to_index()] += 1;
+            }                                                                  //     }
+
+            /// Returns the number of occurrences of the kind.
+            pub const fn get(&self, kind: #kind_name) -> usize {               //     pub const fn get(&self, kind: DrinkKind) -> usize {
+                self.counts[kind.to_index()]                                   //         self.counts[kind.to_index()]
+            }                                                                  //     }
+
+            /// Returns the number of occurrences of all kinds together.
+            pub fn total(&self) -> usize {                                     //     pub fn total(&self) -> usize {
+                self.counts.iter().sum()                                       //         self.counts.iter().sum()
+            }                                                                  //     }
+
+            /// Returns an iterator over the kinds and their numbers of occurrences in the order of `all()`.
+            pub fn iter(&self) -> impl ::core::iter::Iterator<Item = (#kind_name, usize)> + '_ { // pub fn iter(&self) -> impl Iterator<Item = (DrinkKind, usize)> + '_ {
+                #kind_name::all().iter().map(|kind| (*kind, self.get(*kind)))  //     DrinkKind::all().iter().map(|kind| (*kind, self.get(*kind)))
+            }                                                                  //     }
+        }                                                                      // }
+
+        #[automatically_derived]
+        impl ::core::default::Default for #histogram_name {                    // impl Default for DrinkKindHistogram {
+            fn default() -> Self {                                             //     fn default() -> Self {
+                Self::new()                                                    //         Self::new()
+            }                                                                  //     }
+        }                                                                      // }
+
+        #[automatically_derived]
+        impl ::core::iter::Extend<#kind_name> for #histogram_name {            // impl Extend<DrinkKind> for DrinkKindHistogram {
+            fn extend<I: ::core::iter::IntoIterator<Item = #kind_name>>(&mut self, iter: I) { // fn extend<I: IntoIterator<Item = DrinkKind>>(&mut self, iter: I) {
+                for kind in iter {                                             //         for kind in iter {
+                    self.increment(kind);                                      //             self.increment(kind);
+                }                                                              //         }
+            }                                                                  //     }
+        }                                                                      // }
+
+        #[automatically_derived]
+        impl ::core::iter::FromIterator<#kind_name> for #histogram_name {      // impl FromIterator<DrinkKind> for DrinkKindHistogram {
+            fn from_iter<I: ::core::iter::IntoIterator<Item = #kind_name>>(iter: I) -> Self { // fn from_iter<I: IntoIterator<Item = DrinkKind>>(iter: I) -> Self {
+                let mut histogram = Self::new();                               //         let mut histogram = Self::new();
+                histogram.extend(iter);                                        //         histogram.extend(iter);
+                histogram                                                      //         histogram
+            }                                                                  //     }
+        }                                                                      // }
+    )
+}
@@ -0,0 +1,53 @@
+use crate::models::Meta;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Generate the iterator type returned by `iter()` of the kind type, so it can be named,
+/// e.g. in struct fields, unlike `impl Iterator`.
+pub fn gen_iter(meta: &Meta) -> TokenStream {
+    // The external kind type has no iter()
+    if meta.kinded_attrs.external.is_some() {
+        return quote!();
+    }
+
+    let vis = meta.kind_vis();
+    let kind_name = meta.kind_name();
+    let iter_name = meta.iter_name();
+    let doc = format!(
+        "Iterator over [`{kind_name}`] variants in the order of `all()`, returned by `{kind_name}::iter()`."
+    );
+
+    quote!(
+        #[doc = #doc]                                                          // /// Iterator over [`DrinkKind`] variants in the order of `all()`, ..
+        #[derive(::core::clone::Clone, ::core::fmt::Debug)]                    // #[derive(Clone, Debug)]
+        #vis struct #iter_name {                                               // pub struct DrinkKindIter {
+            kinds: ::core::array::IntoIter<#kind_name, { #kind_name::COUNT }>, //     kinds: core::array::IntoIter<DrinkKind, { DrinkKind::COUNT }>,
+        }                                                                      // }
+
+        #[automatically_derived]
+        impl ::core::iter::Iterator for #iter_name {                           // impl Iterator for DrinkKindIter {
+            type Item = #kind_name;                                            //     type Item = DrinkKind;
+                                                                               //
+            fn next(&mut self) -> ::core::option::Option<#kind_name> {         //     fn next(&mut self) -> Option<DrinkKind> {
+                self.kinds.next()                                              //         self.kinds.next()
+            }                                                                  //     }
+                                                                               //
+            fn size_hint(&self) -> (usize, ::core::option::Option<usize>) {    //     fn size_hint(&self) -> (usize, Option<usize>) {
+                self.kinds.size_hint()                                         //         self.kinds.size_hint()
+            }                                                                  //     }
+        }                                                                      // }
+
+        #[automatically_derived]
+        impl ::core::iter::DoubleEndedIterator for #iter_name {                // impl DoubleEndedIterator for DrinkKindIter {
+            fn next_back(&mut self) -> ::core::option::Option<#kind_name> {    //     fn next_back(&mut self) -> Option<DrinkKind> {
+                self.kinds.next_back()                                         //         self.kinds.next_back()
+            }                                                                  //     }
+        }                                                                      // }
+
+        #[automatically_derived]
+        impl ::core::iter::ExactSizeIterator for #iter_name {}                 // impl ExactSizeIterator for DrinkKindIter {}
+
+        #[automatically_derived]
+        impl ::core::iter::FusedIterator for #iter_name {}                     // impl FusedIterator for DrinkKindIter {}
+    )
+}
@@ -1,40 +1,499 @@
-use crate::models::{DisplayCase, Meta};
-use proc_macro2::{Ident, TokenStream};
+use crate::models::{to_lowercase, AllOrder, DisplayCase, FieldsType, Meta, Variant};
+use proc_macro2::TokenStream;
 use quote::quote;
 
 pub fn gen_kind_enum(meta: &Meta) -> TokenStream {
+    // With `external` the kind type is written by the user
+    if meta.kinded_attrs.external.is_some() {
+        return quote!();
+    }
+
     let kind_enum_definition = gen_definition(meta);
     let impl_from_traits = gen_impl_from_traits(meta);
     let impl_display_trait = gen_impl_display_trait(meta);
+    let impl_error_trait = gen_impl_error_trait(meta);
+    let impl_hash_trait = gen_impl_hash_trait(meta);
+    let impl_as_ref_str_trait = gen_impl_as_ref_str_trait(meta);
+    let impl_partial_eq_str_traits = gen_impl_partial_eq_str_traits(meta);
     let impl_from_str_trait = gen_impl_from_str_trait(meta);
+    let impl_try_from_str_traits = gen_impl_try_from_str_traits(meta);
     let impl_kind_trait = gen_impl_kind_trait(meta);
+    let impl_serde_traits = gen_impl_serde_traits(meta);
+    let impl_clap_value_enum_trait = gen_impl_clap_value_enum_trait(meta);
+    let impl_strum_traits = gen_impl_strum_traits(meta);
+    let impl_default_trait = gen_impl_default_trait(meta);
 
     quote!(
         #kind_enum_definition
         #impl_from_traits
         #impl_display_trait
+        #impl_error_trait
+        #impl_hash_trait
+        #impl_as_ref_str_trait
+        #impl_partial_eq_str_traits
         #impl_from_str_trait
+        #impl_try_from_str_traits
         #impl_kind_trait
+        #impl_serde_traits
+        #impl_clap_value_enum_trait
+        #impl_strum_traits
+        #impl_default_trait
     )
 }
 
 fn gen_definition(meta: &Meta) -> TokenStream {
-    let vis = &meta.vis;
+    let vis = meta.kind_vis();
     let kind_name = meta.kind_name();
-    let variant_names: Vec<&Ident> = meta.variants.iter().map(|v| &v.ident).collect();
+    let variant_definitions =
+        meta.kind_variants()
+            .zip(meta.kind_discriminants())
+            .map(|(variant, discriminant)| {
+                let docs = &variant.docs;
+                let cfgs = &variant.cfgs;
+                let ident = meta.kind_variant_ident(&variant.ident);
+                let maybe_discriminant = discriminant.map(|discriminant| quote!(= #discriminant));
+                quote!(#(#docs)* #(#cfgs)* #ident #maybe_discriminant)
+            });
+    let doc = format!("Kind of [`{}`].", meta.ident);
     let traits = meta.derive_traits();
+    let fn_as_str = gen_fn_as_str(meta);
+    let fn_to_index = gen_fn_to_index(meta);
+    let fn_arity = gen_fn_arity(meta);
+    let fn_fields_type = gen_fn_fields_type(meta);
+    let fns_stable_id = gen_fns_stable_id(meta);
+    let fns_is_variant = gen_fns_is_variant(meta);
+    let index_consts = gen_index_consts(meta);
+    let count = gen_count(meta, meta.kind_variants());
+    let kind_path = |variant: &Variant| {
+        let cfgs = &variant.cfgs;
+        let ident = meta.kind_variant_ident(&variant.ident);
+        quote!(#(#cfgs)* #kind_name::#ident)
+    };
+    let all_kinds = meta.all_kind_variants().into_iter().map(kind_path);
+    let iter_name = meta.iter_name();
+    // The lookup table stays in the declaration order, when `all_order` reorders `ALL`
+    let (index_to_kind, all_order) = match meta.kinded_attrs.all_order {
+        Some(AllOrder::Name) => {
+            let kinds = meta.kind_variants().map(kind_path);
+            (quote!([#(#kinds),*]), "sorted by the display names")
+        }
+        Some(AllOrder::Declaration) | None => (quote!(Self::ALL), "in the declaration order"),
+    };
+    let all_doc = format!("All kind variants {all_order}.");
+    let fn_all_doc = format!("Returns a slice with all kind variants {all_order}.");
+    let display_names = meta.kind_variants().map(|variant| {
+        let cfgs = &variant.cfgs;
+        let display_name = meta.display_name(variant);
+        quote!(#(#cfgs)* #display_name)
+    });
+    let maybe_repr = meta.kind_repr().map(|repr| quote!(#[repr(#repr)]));
+    let attrs = meta.kinded_attrs.attrs.iter().flatten();
+    let maybe_non_exhaustive = if meta.kind_non_exhaustive() {
+        quote!(#[non_exhaustive])
+    } else {
+        quote!()
+    };
+    // The common prefix is intended, so it's not reported
+    let maybe_allow_variant_names = if meta.kinded_attrs.variant_prefix.is_some() {
+        quote!(#[allow(clippy::enum_variant_names)])
+    } else {
+        quote!()
+    };
+    let maybe_must_use = if meta.kinded_attrs.must_use {
+        quote!(#[must_use])
+    } else {
+        quote!()
+    };
 
-    quote!(
+    // Without Copy the kind can't be taken out of the constants or passed by value without being consumed,
+    // so only the items taking the kind by reference are generated, see derives_copy()
+    let by_value_items = if meta.derives_copy() {
+        quote!(
+            /// Lookup table from the index in the declaration order to the kind, used by `from_index()`.
+            pub const INDEX_TO_KIND: [#kind_name; Self::COUNT] = #index_to_kind; //   pub const INDEX_TO_KIND: [DrinkKind; Self::COUNT] = Self::ALL;
+
+            #index_consts                                                      //     pub const MATE_INDEX: usize = 0;
+
+            /// Returns an iterator over all kind variants paired with their display names
+            /// in the order of `all()`.
+            pub fn all_named() -> impl ::core::iter::Iterator<Item = (Self, &'static str)> { // pub fn all_named() -> impl Iterator<Item = (Self, &'static str)> {
+                Self::all().iter().map(|kind| (*kind, kind.as_str()))          //         Self::all().iter().map(|kind| (*kind, kind.as_str()))
+            }                                                                  //     }
+
+            /// Returns an iterator over all kind variants except `self` in the order of `all()`.
+            pub fn others(self) -> impl ::core::iter::Iterator<Item = Self> {  //     pub fn others(self) -> impl Iterator<Item = Self> {
+                let index = self.to_index();                                   //         let index = self.to_index();
+                Self::all()                                                    //         Self::all()
+                    .iter()                                                    //             .iter()
+                    .copied()                                                  //             .copied()
+                    .filter(move |kind| kind.to_index() != index)              //             .filter(move |kind| kind.to_index() != index)
+            }                                                                  //     }
+
+            /// Returns an iterator over the kinds satisfying the predicate in the order of `all()`.
+            pub fn matching(                                                   //     pub fn matching(
+                predicate: impl ::core::ops::Fn(Self) -> bool,                 //         predicate: impl Fn(Self) -> bool,
+            ) -> impl ::core::iter::Iterator<Item = Self> {                    //     ) -> impl Iterator<Item = Self> {
+                Self::all()                                                    //         Self::all()
+                    .iter()                                                    //             .iter()
+                    .copied()                                                  //             .copied()
+                    .filter(move |kind| predicate(*kind))                      //             .filter(move |kind| predicate(*kind))
+            }                                                                  //     }
+
+            /// Returns the kind with exactly the given display name, or `None`.
+            /// Unlike `FromStr`, no other spellings are accepted.
+            pub fn by_name(name: &str) -> ::core::option::Option<Self> {       //     pub fn by_name(name: &str) -> Option<Self> {
+                Self::names()                                                  //         Self::names()
+                    .iter()                                                    //             .iter()
+                    .position(|known| *known == name)                          //             .position(|known| *known == name)
+                    .and_then(Self::from_index)                                //             .and_then(Self::from_index)
+            }                                                                  //     }
+
+            /// Returns an iterator over the kinds within the range in the declaration order,
+            /// e.g. `DrinkKind::range(DrinkKind::Coffee..=DrinkKind::Tea)`.
+            pub fn range(                                                      //     pub fn range(
+                range: impl ::core::ops::RangeBounds<Self>,                    //         range: impl RangeBounds<Self>,
+            ) -> impl ::core::iter::Iterator<Item = Self> {                    //     ) -> impl Iterator<Item = Self> {
+                use ::core::ops::Bound;                                        //         use core::ops::Bound;
+                let start = match range.start_bound() {                        //         let start = match range.start_bound() {
+                    Bound::Included(kind) => kind.to_index(),                  //             Bound::Included(kind) => kind.to_index(),
+                    Bound::Excluded(kind) => kind.to_index() + 1,              //             Bound::Excluded(kind) => kind.to_index() + 1,
+                    Bound::Unbounded => 0,                                     //             Bound::Unbounded => 0,
+                };                                                             //         };
+                let end = match range.end_bound() {                            //         let end = match range.end_bound() {
+                    Bound::Included(kind) => kind.to_index() + 1,              //             Bound::Included(kind) => kind.to_index() + 1,
+                    Bound::Excluded(kind) => kind.to_index(),                  //             Bound::Excluded(kind) => kind.to_index(),
+                    Bound::Unbounded => Self::COUNT,                           //             Bound::Unbounded => Self::COUNT,
+                };                                                             //         };
+                // An empty slice for reversed ranges, e.g. `Tea..Coffee`
+                let kinds: &'static [Self] = &Self::INDEX_TO_KIND;             //         let kinds: &'static [Self] = &Self::INDEX_TO_KIND;
+                kinds                                                          //         kinds
+                    .get(start..end)                                           //             .get(start..end)
+                    .unwrap_or_default()                                       //             .unwrap_or_default()
+                    .iter()                                                    //             .iter()
+                    .copied()                                                  //             .copied()
+            }                                                                  //     }
+
+            #fn_to_index                                                       //     pub const fn to_index(self) -> usize { .. }
+
+            #fn_arity                                                          //     pub const fn arity(self) -> usize { .. }
+
+            #fn_fields_type                                                    //     pub const fn fields_type(self) -> ::kinded::FieldsType { .. }
+
+            #fns_stable_id                                                     //     pub const fn stable_id(self) -> u32 { .. }
+
+            #fns_is_variant                                                    //     pub const fn is_mate(self) -> bool { .. }
+
+            /// Returns the kind at the given position in the declaration order.
+            pub const fn from_index(index: usize) -> ::core::option::Option<Self> { // pub const fn from_index(index: usize) -> Option<Self> {
+                if index < Self::COUNT {                                       //         if index < Self::COUNT {
+                    ::core::option::Option::Some(Self::INDEX_TO_KIND[index])   //             Some(Self::INDEX_TO_KIND[index])
+                } else {                                                       //         } else {
+                    ::core::option::Option::None                               //             None
+                }                                                              //         }
+            }                                                                  //     }
+
+            /// Returns the next kind in the declaration order, wrapping around to the first one.
+            pub fn next(self) -> Self {                                        //     pub fn next(self) -> Self {
+                let kinds: &'static [Self] = &Self::INDEX_TO_KIND;             //         let kinds: &'static [Self] = &Self::INDEX_TO_KIND;
+                match self.checked_next() {                                    //         match self.checked_next() {
+                    ::core::option::Option::Some(next) => next,                //             Some(next) => next,
+                    ::core::option::Option::None => kinds[0],                  //             None => kinds[0],
+                }                                                              //         }
+            }                                                                  //     }
+
+            /// Returns the previous kind in the declaration order, wrapping around to the last one.
+            pub fn prev(self) -> Self {                                        //     pub fn prev(self) -> Self {
+                let kinds: &'static [Self] = &Self::INDEX_TO_KIND;             //         let kinds: &'static [Self] = &Self::INDEX_TO_KIND;
+                match self.checked_prev() {                                    //         match self.checked_prev() {
+                    ::core::option::Option::Some(prev) => prev,                //             Some(prev) => prev,
+                    ::core::option::Option::None => kinds[kinds.len() - 1],    //             None => kinds[kinds.len() - 1],
+                }                                                              //         }
+            }                                                                  //     }
+
+            /// Returns the next kind in the declaration order, or `None` for the last one.
+            pub fn checked_next(self) -> ::core::option::Option<Self> {        //     pub fn checked_next(self) -> Option<Self> {
+                Self::from_index(self.to_index() + 1)                          //         Self::from_index(self.to_index() + 1)
+            }                                                                  //     }
+
+            /// Returns the previous kind in the declaration order, or `None` for the first one.
+            pub fn checked_prev(self) -> ::core::option::Option<Self> {        //     pub fn checked_prev(self) -> Option<Self> {
+                self.to_index().checked_sub(1).and_then(Self::from_index)      //         self.to_index().checked_sub(1).and_then(Self::from_index)
+            }                                                                  //     }
+        )
+    } else {
+        quote!()
+    };
+
+    let kind_enum = quote!(
+        #[doc = #doc]                                                          // /// Kind of [`Drink`].
         #[derive(#(#traits),*)]                                                // #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        #[allow(dead_code)]
+        #maybe_repr                                                            // #[repr(u8)]
+        #maybe_non_exhaustive                                                  // #[non_exhaustive]
+        #maybe_allow_variant_names                                             // #[allow(clippy::enum_variant_names)]
+        #maybe_must_use                                                        // #[must_use]
+        #(#[#attrs])*                                                          // #[serde(rename_all = "kebab-case")]
         #vis enum #kind_name {                                                 // pub enum DrinkKind {
-            #(#variant_names),*                                                //     Mate, Coffee, Tea
-        }                                                                      // }
+            #(#variant_definitions),*                                          //     /// Yerba mate
+        }                                                                      //     Mate, Coffee, Tea
+                                                                               // }
+    );
+    quote!(
+        #kind_enum
 
+        #[allow(dead_code)]
         impl #kind_name {                                                      // impl DrinkKind {
+            /// The number of kind variants.
+            pub const COUNT: usize = #count;                                   //     pub const COUNT: usize = 3;
+
+            #[doc = #all_doc]                                                  //     /// All kind variants in the declaration order.
+            pub const ALL: [#kind_name; Self::COUNT] = [                       //     pub const ALL: [DrinkKind; Self::COUNT] = [
+                #(#all_kinds),*                                                //         DrinkKind::Mate, DrinkKind::Coffee, DrinkKind::Tea
+            ];                                                                 //     ];
+
+            /// Display names of all kind variants in the declaration order.
+            pub const NAMES: [&'static str; Self::COUNT] = [                   //     pub const NAMES: [&'static str; Self::COUNT] = [
+                #(#display_names),*                                            //         "Mate", "Coffee", "Tea"
+            ];                                                                 //     ];
+
+            #[doc = #fn_all_doc]                                               //     /// Returns a slice with all kind variants in the declaration order.
             pub fn all() -> &'static [#kind_name] {                            //     pub fn all() -> &'static [DrinkKind] {
-                &[                                                             //         &[
-                    #(#kind_name::#variant_names),*                            //             DrinkKind::Mate, DrinkKind::Coffee, DrinkKind::Tea
-                ]                                                              //         ]
+                &Self::ALL                                                     //         &Self::ALL
+            }                                                                  //     }
+
+            /// Returns an iterator over all kind variants in the order of `all()`,
+            /// which yields the kinds by value, can be reversed and knows its length.
+            pub fn iter() -> #iter_name {                                      //     pub fn iter() -> DrinkKindIter {
+                #iter_name { kinds: Self::ALL.into_iter() }                    //         DrinkKindIter { kinds: Self::ALL.into_iter() }
+            }                                                                  //     }
+
+            /// Returns a slice with display names of all kind variants in the declaration order.
+            pub fn names() -> &'static [&'static str] {                        //     pub fn names() -> &'static [&'static str] {
+                &Self::NAMES                                                   //         &Self::NAMES
+            }                                                                  //     }
+
+            /// Calls the closure with every kind variant in the order of `all()`,
+            /// e.g. to fill a dispatch table.
+            pub fn for_each_kind(mut f: impl ::core::ops::FnMut(Self)) {       //     pub fn for_each_kind(mut f: impl FnMut(Self)) {
+                for kind in Self::ALL {                                        //         for kind in Self::ALL {
+                    f(kind);                                                   //             f(kind);
+                }                                                              //         }
+            }                                                                  //     }
+
+            #fn_as_str                                                         //     pub const fn as_str(&self) -> &'static str { .. }
+
+            #by_value_items                                                    //     pub const fn to_index(self) -> usize { .. }
+        }                                                                      // }
+    )
+}
+
+/// Generate the number of the given variants. Variants with `#[cfg(..)]` are counted only when
+/// they are compiled in, so the number can't be known in advance.
+fn gen_count<'a>(meta: &Meta, variants: impl Iterator<Item = &'a Variant>) -> TokenStream {
+    if meta.has_cfg_variants() {
+        let units = variants.map(|variant| {
+            let cfgs = &variant.cfgs;
+            quote!(#(#cfgs)* ())
+        });
+        quote!(
+            {                                                                  // {
+                let enabled: &[()] = &[#(#units),*];                           //     let enabled: &[()] = &[#[cfg(..)] (), ()];
+                enabled.len()                                                  //     enabled.len()
+            }                                                                  // }
+        )
+    } else {
+        let count = variants.count();
+        quote!(#count)
+    }
+}
+
+fn gen_fn_as_str(meta: &Meta) -> TokenStream {
+    let kind_name = meta.kind_name();
+
+    let match_branches = meta.kind_variants().map(|variant| {
+        let variant_name = meta.kind_variant_ident(&variant.ident);
+        let cfgs = &variant.cfgs;
+        let display_name = meta.display_name(variant);
+        quote!(
+            #(#cfgs)* #kind_name::#variant_name => #display_name
+        )
+    });
+
+    quote!(
+        /// Returns the display name of the kind without allocation.
+        pub const fn as_str(&self) -> &'static str {                           // pub const fn as_str(&self) -> &'static str {
+            match *self {                                                      //     match *self {
+                #(#match_branches),*                                           //         DrinkKind::Mate => "mate",
+            }                                                                  //     }
+        }                                                                      // }
+    )
+}
+
+fn gen_fn_to_index(meta: &Meta) -> TokenStream {
+    let kind_name = meta.kind_name();
+
+    let variants: Vec<&Variant> = meta.kind_variants().collect();
+    let match_branches = variants.iter().enumerate().map(|(index, variant)| {
+        let variant_name = meta.kind_variant_ident(&variant.ident);
+        let cfgs = &variant.cfgs;
+        let index = gen_count(meta, variants[..index].iter().copied());
+        quote!(
+            #(#cfgs)* #kind_name::#variant_name => #index
+        )
+    });
+
+    quote!(
+        /// Returns the position of the kind in the declaration order.
+        pub const fn to_index(self) -> usize {                                 // pub const fn to_index(self) -> usize {
+            match self {                                                       //     match self {
+                #(#match_branches),*                                           //         DrinkKind::Mate => 0,
+            }                                                                  //     }
+        }                                                                      // }
+    )
+}
+
+fn gen_fn_arity(meta: &Meta) -> TokenStream {
+    let kind_name = meta.kind_name();
+
+    let match_branches = meta.kind_variants().map(|variant| {
+        let variant_name = meta.kind_variant_ident(&variant.ident);
+        let cfgs = &variant.cfgs;
+        let fields_count = variant.fields_count;
+        quote!(
+            #(#cfgs)* #kind_name::#variant_name => #fields_count
+        )
+    });
+
+    quote!(
+        /// Returns the number of fields of the corresponding variant.
+        pub const fn arity(self) -> usize {                                    // pub const fn arity(self) -> usize {
+            match self {                                                       //     match self {
+                #(#match_branches),*                                           //         DrinkKind::Tea => 2,
+            }                                                                  //     }
+        }                                                                      // }
+    )
+}
+
+/// Items generated for a `#[doc(hidden)]` variant are hidden too, so they don't reveal it in the docs.
+/// The kind variant itself gets the attribute with the other doc attributes.
+fn gen_maybe_doc_hidden(variant: &Variant) -> TokenStream {
+    if variant.doc_hidden {
+        quote!(#[doc(hidden)])
+    } else {
+        quote!()
+    }
+}
+
+fn gen_index_consts(meta: &Meta) -> TokenStream {
+    let kind_name = meta.kind_name();
+
+    let consts = meta
+        .kind_variants()
+        .filter(|variant| meta.has_unique_predicate_name(variant))
+        .map(|variant| {
+        let variant_name = meta.kind_variant_ident(&variant.ident);
+        let cfgs = &variant.cfgs;
+        let const_name = meta.index_const_name(variant);
+        let doc = format!("Index of [`{kind_name}::{variant_name}`] in the declaration order, the same as `to_index()`.");
+        let maybe_doc_hidden = gen_maybe_doc_hidden(variant);
+        quote!(
+            #[doc = #doc]                                                      // /// Index of [`DrinkKind::Mate`] in the declaration order, ..
+            #maybe_doc_hidden                                                  // #[doc(hidden)]
+            #(#cfgs)*                                                          // #[cfg(..)]
+            pub const #const_name: usize = Self::#variant_name.to_index();     // pub const MATE_INDEX: usize = Self::Mate.to_index();
+        )
+    });
+
+    quote!(#(#consts)*)
+}
+
+fn gen_fns_is_variant(meta: &Meta) -> TokenStream {
+    let kind_name = meta.kind_name();
+
+    let predicates = meta
+        .kind_variants()
+        .filter(|variant| meta.has_unique_predicate_name(variant))
+        .map(|variant| {
+            let variant_name = meta.kind_variant_ident(&variant.ident);
+            let cfgs = &variant.cfgs;
+            let fn_name = meta.predicate_name(variant);
+            let doc = format!("Returns `true` if the kind is [`{kind_name}::{variant_name}`].");
+            let maybe_doc_hidden = gen_maybe_doc_hidden(variant);
+            quote!(
+                #[doc = #doc]                                                      // /// Returns `true` if the kind is [`DrinkKind::Mate`].
+                #maybe_doc_hidden                                                  // #[doc(hidden)]
+                #(#cfgs)*                                                          // #[cfg(..)]
+                pub const fn #fn_name(self) -> bool {                              // pub const fn is_mate(self) -> bool {
+                    ::core::matches!(self, #kind_name::#variant_name)              //     matches!(self, DrinkKind::Mate)
+                }                                                                  // }
+            )
+        });
+
+    quote!(#(#predicates)*)
+}
+
+fn gen_fn_fields_type(meta: &Meta) -> TokenStream {
+    let kind_name = meta.kind_name();
+
+    let match_branches = meta.kind_variants().map(|variant| {
+        let variant_name = meta.kind_variant_ident(&variant.ident);
+        let fields_type = match variant.fields_type {
+            FieldsType::Named => quote!(::kinded::FieldsType::Named),
+            FieldsType::Unnamed => quote!(::kinded::FieldsType::Unnamed),
+            FieldsType::Unit => quote!(::kinded::FieldsType::Unit),
+        };
+        let cfgs = &variant.cfgs;
+        quote!(
+            #(#cfgs)* #kind_name::#variant_name => #fields_type
+        )
+    });
+
+    quote!(
+        /// Returns whether the corresponding variant has named, unnamed or no fields.
+        pub const fn fields_type(self) -> ::kinded::FieldsType {               // pub const fn fields_type(self) -> ::kinded::FieldsType {
+            match self {                                                       //     match self {
+                #(#match_branches),*                                           //         DrinkKind::Tea => ::kinded::FieldsType::Named,
+            }                                                                  //     }
+        }                                                                      // }
+    )
+}
+
+fn gen_fns_stable_id(meta: &Meta) -> TokenStream {
+    if !meta.has_stable_ids() {
+        return quote!();
+    }
+
+    let kind_name = meta.kind_name();
+
+    // All the kind variants have ids, see validate_stable_ids()
+    let variants_with_ids = meta
+        .kind_variants()
+        .filter_map(|variant| variant.kinded_attrs.id.map(|(id, _)| (variant, id)));
+    let (to_id_branches, from_id_branches): (Vec<_>, Vec<_>) = variants_with_ids
+        .map(|(variant, id)| {
+            let variant_name = meta.kind_variant_ident(&variant.ident);
+            let cfgs = &variant.cfgs;
+            let to_id = quote!(#(#cfgs)* #kind_name::#variant_name => #id);
+            let from_id =
+                quote!(#(#cfgs)* #id => ::core::option::Option::Some(#kind_name::#variant_name));
+            (to_id, from_id)
+        })
+        .unzip();
+
+    quote!(
+        /// Returns the stable id of the kind, given with `#[kinded(id = ...)]`.
+        /// Unlike `to_index()`, it does not change when the variants are reordered.
+        pub const fn stable_id(self) -> u32 {                                  // pub const fn stable_id(self) -> u32 {
+            match self {                                                       //     match self {
+                #(#to_id_branches),*                                           //         DrinkKind::Mate => 7,
+            }                                                                  //     }
+        }                                                                      // }
+
+        /// Returns the kind with the given stable id, or `None` if there is no such kind.
+        pub const fn from_stable_id(id: u32) -> ::core::option::Option<Self> { // pub const fn from_stable_id(id: u32) -> Option<Self> {
+            match id {                                                         //     match id {
+                #(#from_id_branches,)*                                         //         7 => Some(DrinkKind::Mate),
+                _ => ::core::option::Option::None,                             //         _ => None,
             }                                                                  //     }
         }                                                                      // }
     )
@@ -42,19 +501,38 @@ fn gen_definition(meta: &Meta) -> TokenStream {
 
 fn gen_impl_from_traits(meta: &Meta) -> TokenStream {
     let kind_name = meta.kind_name();
-    let generics = &meta.generics;
+    let kind_method = meta.kind_method();
+    let (impl_generics, _, where_clause) = meta.generics.split_for_impl();
     let main_enum_with_generics = meta.main_enum_with_generics();
 
     quote!(
-        impl #generics From<#main_enum_with_generics> for #kind_name {         // impl<T> From<Drink<T>> for DrinkKind {
+        #[automatically_derived]
+        impl #impl_generics ::core::convert::From<#main_enum_with_generics> for #kind_name // impl<T> From<Drink<T>> for DrinkKind
+        #where_clause {                                                        // where .. {
             fn from(value: #main_enum_with_generics) -> #kind_name {           //     fn from(value: Drink<T>) -> DrinkKind {
-                value.kind()                                                   //         value.kind()
+                value.#kind_method()                                           //         value.kind()
             }                                                                  //     }
         }                                                                      // }
 
-        impl #generics From<&#main_enum_with_generics> for #kind_name {        // impl<T> From<Drink<T>> for DrinkKind {
+        #[automatically_derived]
+        impl #impl_generics ::core::convert::From<&#main_enum_with_generics> for #kind_name // impl<T> From<&Drink<T>> for DrinkKind
+        #where_clause {                                                        // where .. {
             fn from(value: &#main_enum_with_generics) -> #kind_name {          //     fn from(value: &Drink<T>) -> DrinkKind {
-                value.kind()                                                   //         value.kind()
+                value.#kind_method()                                           //         value.kind()
+            }                                                                  //     }
+        }                                                                      // }
+
+        #[automatically_derived]
+        impl ::core::convert::From<#kind_name> for &'static str {              // impl From<DrinkKind> for &'static str {
+            fn from(kind: #kind_name) -> &'static str {                        //     fn from(kind: DrinkKind) -> &'static str {
+                kind.as_str()                                                  //         kind.as_str()
+            }                                                                  //     }
+        }                                                                      // }
+
+        #[automatically_derived]
+        impl ::core::convert::From<#kind_name> for ::kinded::__private::String { // impl From<DrinkKind> for String {
+            fn from(kind: #kind_name) -> ::kinded::__private::String {         //     fn from(kind: DrinkKind) -> String {
+                ::kinded::__private::String::from(kind.as_str())               //         String::from(kind.as_str())
             }                                                                  //     }
         }                                                                      // }
     )
@@ -62,62 +540,158 @@ fn gen_impl_from_traits(meta: &Meta) -> TokenStream {
 
 fn gen_impl_display_trait(meta: &Meta) -> TokenStream {
     let kind_name = meta.kind_name();
-    let maybe_case = meta.kinded_attrs.display;
-
-    let match_branches = meta.variants.iter().map(|variant| {
-        let original_variant_name_str = variant.ident.to_string();
-        let cased_variant_name = apply_maybe_case(original_variant_name_str, maybe_case);
-        let variant_name = &variant.ident;
-        quote!(
-            #kind_name::#variant_name => write!(f, #cased_variant_name)
-        )
-    });
 
     quote!(
-        impl core::fmt::Display for #kind_name {                                    // impl core::fmt::Display for DrinkKind {
-            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {  //     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-                match self {                                                        //         match self {
-                    #(#match_branches),*                                            //             DrinkKind::Mate => write!(f, "mate"),
-                }                                                                   //         }
+        #[automatically_derived]
+        impl ::core::fmt::Display for #kind_name {                                  // impl core::fmt::Display for DrinkKind {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result { //     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                // pad() applies the width, alignment and precision flags, e.g. `{:>6}`
+                f.pad(self.as_str())                                                //         f.pad(self.as_str())
             }                                                                       //     }
-        }                                                                           //
+        }                                                                           // }
     )
 }
 
-fn apply_maybe_case(original: String, maybe_display_case: Option<DisplayCase>) -> String {
-    if let Some(display_case) = maybe_display_case {
-        display_case.apply(&original)
-    } else {
-        original
+fn gen_impl_error_trait(meta: &Meta) -> TokenStream {
+    if !meta.kinded_attrs.error {
+        return quote!();
     }
+
+    let kind_name = meta.kind_name();
+
+    quote!(
+        #[automatically_derived]
+        impl ::kinded::__private::Error for #kind_name {}                      // impl std::error::Error for DrinkKind {}
+    )
+}
+
+/// Hash a single integer, which doesn't depend on the discriminants: the stable id if the kinds have it,
+/// otherwise the index in the declaration order. It's hashed as `u64`, so it's the same on all platforms.
+fn gen_impl_hash_trait(meta: &Meta) -> TokenStream {
+    if !meta.hashes_kind() {
+        return quote!();
+    }
+
+    let kind_name = meta.kind_name();
+    let value = if meta.has_stable_ids() {
+        quote!(self.stable_id() as u64)
+    } else {
+        quote!(self.to_index() as u64)
+    };
+
+    quote!(
+        #[automatically_derived]
+        impl ::core::hash::Hash for #kind_name {                               // impl Hash for DrinkKind {
+            fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {           //     fn hash<H: Hasher>(&self, state: &mut H) {
+                ::core::hash::Hasher::write_u64(state, #value);                //         state.write_u64(self.to_index() as u64);
+            }                                                                  //     }
+        }                                                                      // }
+    )
+}
+
+fn gen_impl_as_ref_str_trait(meta: &Meta) -> TokenStream {
+    let kind_name = meta.kind_name();
+
+    quote!(
+        #[automatically_derived]
+        impl ::core::convert::AsRef<str> for #kind_name {                      // impl AsRef<str> for DrinkKind {
+            fn as_ref(&self) -> &str {                                         //     fn as_ref(&self) -> &str {
+                self.as_str()                                                  //         self.as_str()
+            }                                                                  //     }
+        }                                                                      // }
+    )
+}
+
+/// Compare a kind with its display name, e.g. `DrinkKind::Mate == *"Mate"`.
+/// Unlike parsing, the comparison is exact: no other casing is accepted.
+/// Only `str` is supported: every extra `PartialEq` impl makes type inference harder for users.
+fn gen_impl_partial_eq_str_traits(meta: &Meta) -> TokenStream {
+    let kind_name = meta.kind_name();
+
+    quote!(
+        #[automatically_derived]
+        impl ::core::cmp::PartialEq<str> for #kind_name {                      // impl PartialEq<str> for DrinkKind {
+            fn eq(&self, other: &str) -> bool {                                //     fn eq(&self, other: &str) -> bool {
+                self.as_str() == other                                         //         self.as_str() == other
+            }                                                                  //     }
+        }                                                                      // }
+    )
 }
 
 fn gen_impl_from_str_trait(meta: &Meta) -> TokenStream {
+    if meta.kinded_attrs.no_from_str {
+        return quote!();
+    }
+
     let kind_name = meta.kind_name();
 
-    let original_match_branches = meta.variants.iter().map(|variant| {
-        let ident = &variant.ident;
-        let name_str = ident.to_string();
-        quote!(#name_str => return Ok(#kind_name::#ident),)
+    let display_match_branches = meta.kind_variants().map(|variant| {
+        let ident = meta.kind_variant_ident(&variant.ident);
+        let cfgs = &variant.cfgs;
+        let display_name = meta.display_name(variant);
+        quote!(#(#cfgs)* #display_name => return ::core::result::Result::Ok(#kind_name::#ident),)
     });
 
-    let alt_match_branches = meta.variants.iter().map(|variant| {
-        let ident = &variant.ident;
-        let name_str = ident.to_string();
+    // If display is customized, the original variant names should be tried as well
+    let original_match = if meta.has_custom_display() {
+        let original_match_branches = meta.kind_variants().map(|variant| {
+            let ident = meta.kind_variant_ident(&variant.ident);
+            let cfgs = &variant.cfgs;
+            let name_str = variant.ident.to_string();
+            quote!(#(#cfgs)* #name_str => return ::core::result::Result::Ok(#kind_name::#ident),)
+        });
+        quote!(
+            match s {                                                          // match s {
+                #(#original_match_branches)*                                   //     "HotMate" => Mate::HotMate,
+                _ => ()                                                        //      _ => (),
+            }                                                                  // }
+        )
+    } else {
+        quote!()
+    };
+
+    let alt_match_branches = meta.kind_variants().map(|variant| {
+        let ident = meta.kind_variant_ident(&variant.ident);
+        let cfgs = &variant.cfgs;
+        let name_str = variant.ident.to_string();
         let alternatives = DisplayCase::all().map(|case| case.apply(&name_str));
-        quote!(#(#alternatives)|* => return Ok(#kind_name::#ident),)
+        quote!(#(#cfgs)* #(#alternatives)|* => return ::core::result::Result::Ok(#kind_name::#ident),)
     });
 
+    // Display names are distinct ignoring case (see validate_display_names),
+    // so the order of the checks does not matter. The case is ignored for all Unicode letters,
+    // lowering every char the same way on both sides
+    let case_insensitive_match = if meta.kinded_attrs.parse_case_insensitive {
+        let checks = meta.kind_variants().map(|variant| {
+            let ident = meta.kind_variant_ident(&variant.ident);
+            let cfgs = &variant.cfgs;
+            let lowercase_name = to_lowercase(&meta.display_name(variant));
+            quote!(
+                #(#cfgs)*                                                      // #[cfg(..)]
+                if s.chars().flat_map(::core::primitive::char::to_lowercase).eq(#lowercase_name.chars()) { // if s.chars().flat_map(char::to_lowercase).eq("hot_mate".chars()) {
+                    return ::core::result::Result::Ok(#kind_name::#ident);     //     return Ok(Mate::HotMate);
+                }                                                              // }
+            )
+        });
+        quote!(#(#checks)*)
+    } else {
+        quote!()
+    };
+
     quote!(
+        #[automatically_derived]
         impl ::core::str::FromStr for #kind_name {
             type Err = ::kinded::ParseKindError;
 
             fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
-                // First try to match the variants as they are
+                // First try to match the variants as they are displayed,
+                // so parsing always round-trips with Display
                 match s {                                                      // match s {
-                    #(#original_match_branches)*                               //     "HotMate" => Mate::HotMate,
+                    #(#display_match_branches)*                                //     "hot_mate" => Mate::HotMate,
                     _ => ()                                                    //      _ => (),
-                }                                                              //
+                }                                                              // }
+
+                #original_match
 
                 // Now try to match all possible alternative spelling of
                 // the variants
@@ -126,11 +700,41 @@ fn gen_impl_from_str_trait(meta: &Meta) -> TokenStream {
                     _ => ()                                                    //      _ => ()
                 }                                                              // }
 
+                #case_insensitive_match
+
                 // If still no success, then return an error
-                extern crate alloc;
-                use alloc::borrow::ToOwned;
-                let error = ::kinded::ParseKindError::from_type_and_string::<#kind_name>(s.to_owned());
-                Err(error)
+                let given_string = ::kinded::__private::String::from(s);
+                let error = ::kinded::ParseKindError::from_type_and_string::<#kind_name>(given_string)
+                    .with_valid_names(Self::names());
+                ::core::result::Result::Err(error)
+            }
+        }
+    )
+}
+
+fn gen_impl_try_from_str_traits(meta: &Meta) -> TokenStream {
+    if meta.kinded_attrs.no_from_str {
+        return quote!();
+    }
+
+    let kind_name = meta.kind_name();
+
+    quote!(
+        #[automatically_derived]
+        impl ::core::convert::TryFrom<&str> for #kind_name {                   // impl TryFrom<&str> for DrinkKind {
+            type Error = ::kinded::ParseKindError;                             //     type Error = ::kinded::ParseKindError;
+                                                                               //
+            fn try_from(value: &str) -> ::core::result::Result<Self, Self::Error> {
+                ::core::str::FromStr::from_str(value)                          //         FromStr::from_str(value)
+            }                                                                  //     }
+        }                                                                      // }
+
+        #[automatically_derived]
+        impl ::core::convert::TryFrom<::kinded::__private::String> for #kind_name {
+            type Error = ::kinded::ParseKindError;
+
+            fn try_from(value: ::kinded::__private::String) -> ::core::result::Result<Self, Self::Error> {
+                ::core::str::FromStr::from_str(&value)
             }
         }
     )
@@ -140,6 +744,7 @@ fn gen_impl_kind_trait(meta: &Meta) -> TokenStream {
     let kind_name = meta.kind_name();
 
     quote!(
+        #[automatically_derived]
         impl ::kinded::Kind for #kind_name {
             fn all() -> &'static [#kind_name] {
                 Self::all()
@@ -147,3 +752,135 @@ fn gen_impl_kind_trait(meta: &Meta) -> TokenStream {
         }
     )
 }
+
+fn gen_impl_default_trait(meta: &Meta) -> TokenStream {
+    let Some(ref default) = meta.kinded_attrs.default else {
+        return quote!();
+    };
+
+    let kind_name = meta.kind_name();
+    let default = meta.kind_variant_ident(default);
+
+    quote!(
+        #[automatically_derived]
+        impl ::core::default::Default for #kind_name {                         // impl Default for DrinkKind {
+            fn default() -> Self {                                             //     fn default() -> Self {
+                #kind_name::#default                                           //         DrinkKind::Mate
+            }                                                                  //     }
+        }                                                                      // }
+    )
+}
+
+fn gen_impl_serde_traits(meta: &Meta) -> TokenStream {
+    if meta.kinded_attrs.serde_repr {
+        return gen_impl_serde_repr_traits(meta);
+    }
+    if !meta.kinded_attrs.serde {
+        return quote!();
+    }
+
+    let kind_name = meta.kind_name();
+
+    quote!(
+        #[automatically_derived]
+        impl ::kinded::__private::serde::Serialize for #kind_name {
+            fn serialize<S>(&self, serializer: S) -> ::core::result::Result<S::Ok, S::Error>
+            where
+                S: ::kinded::__private::serde::Serializer,
+            {
+                serializer.serialize_str(self.as_str())
+            }
+        }
+
+        #[automatically_derived]
+        impl<'de> ::kinded::__private::serde::Deserialize<'de> for #kind_name {
+            fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
+            where
+                D: ::kinded::__private::serde::Deserializer<'de>,
+            {
+                ::kinded::__private::deserialize_kind(deserializer)
+            }
+        }
+    )
+}
+
+/// With `serde_repr` the kind is serialized as its index in the declaration order,
+/// the same way as the `serde_repr` crate serializes enums as their discriminants.
+fn gen_impl_serde_repr_traits(meta: &Meta) -> TokenStream {
+    let kind_name = meta.kind_name();
+
+    quote!(
+        #[automatically_derived]
+        impl ::kinded::__private::serde::Serialize for #kind_name {
+            fn serialize<S>(&self, serializer: S) -> ::core::result::Result<S::Ok, S::Error>
+            where
+                S: ::kinded::__private::serde::Serializer,
+            {
+                serializer.serialize_u32(self.to_index() as u32)
+            }
+        }
+
+        #[automatically_derived]
+        impl<'de> ::kinded::__private::serde::Deserialize<'de> for #kind_name {
+            fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
+            where
+                D: ::kinded::__private::serde::Deserializer<'de>,
+            {
+                ::kinded::__private::deserialize_kind_index(deserializer, &Self::INDEX_TO_KIND)
+            }
+        }
+    )
+}
+
+fn gen_impl_clap_value_enum_trait(meta: &Meta) -> TokenStream {
+    if !meta.kinded_attrs.clap {
+        return quote!();
+    }
+
+    let kind_name = meta.kind_name();
+
+    quote!(
+        #[automatically_derived]
+        impl ::kinded::__private::clap::ValueEnum for #kind_name {
+            fn value_variants<'a>() -> &'a [Self] {
+                &Self::ALL
+            }
+
+            fn to_possible_value(&self) -> ::core::option::Option<::kinded::__private::clap::builder::PossibleValue> {
+                ::core::option::Option::Some(::kinded::__private::clap::builder::PossibleValue::new(self.as_str()))
+            }
+        }
+    )
+}
+
+fn gen_impl_strum_traits(meta: &Meta) -> TokenStream {
+    if !meta.kinded_attrs.strum {
+        return quote!();
+    }
+
+    let kind_name = meta.kind_name();
+    let iter_name = meta.iter_name();
+
+    // FromStr, AsRef<str> and From<DrinkKind> for &'static str are always implemented,
+    // so only the rest of strum's surface is generated here
+    quote!(
+        #[automatically_derived]
+        impl ::kinded::__private::strum::EnumCount for #kind_name {            // impl strum::EnumCount for DrinkKind {
+            const COUNT: usize = Self::COUNT;                                  //     const COUNT: usize = Self::COUNT;
+        }                                                                      // }
+
+        #[automatically_derived]
+        impl ::kinded::__private::strum::VariantNames for #kind_name {         // impl strum::VariantNames for DrinkKind {
+            const VARIANTS: &'static [&'static str] = &Self::NAMES;            //     const VARIANTS: &'static [&'static str] = &Self::NAMES;
+        }                                                                      // }
+
+        #[automatically_derived]
+        impl ::kinded::__private::strum::IntoEnumIterator for #kind_name {     // impl strum::IntoEnumIterator for DrinkKind {
+            type Iterator = #iter_name;                                        //     type Iterator = DrinkKindIter;
+                                                                               //
+            fn iter() -> Self::Iterator {                                      //     fn iter() -> Self::Iterator {
+                #iter_name { kinds: Self::ALL.into_iter() }                    //         DrinkKindIter { kinds: Self::ALL.into_iter() }
+            }                                                                  //     }
+        }                                                                      // }
+    )
+}
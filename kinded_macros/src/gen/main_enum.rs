@@ -1,64 +1,375 @@
-use crate::models::{FieldsType, Meta, Variant};
+use crate::models::{DataType, FieldsType, Meta, Variant};
 use proc_macro2::{Ident, TokenStream};
-use quote::quote;
+use quote::{format_ident, quote};
 
 pub fn gen_main_enum_extra(meta: &Meta) -> TokenStream {
     let fn_kind = gen_fn_kind(meta);
+    let kind_name = meta.kind_path();
+    let kind_method = meta.kind_method();
     let main_enum_with_generics = meta.main_enum_with_generics();
-    let generics = &meta.generics;
+    let (impl_generics, _, where_clause) = meta.generics.split_for_impl();
 
     let impl_kinded_trait = gen_impl_kinded_trait(meta);
 
+    // The external kind type is not known to have to_index() and the other generated methods,
+    // so only the items relying on the Kinded trait bounds are generated
+    if meta.kinded_attrs.external.is_some() {
+        return quote!(
+            #[allow(dead_code)]
+            impl #impl_generics #main_enum_with_generics #where_clause {       // impl<T> Drink<T> {
+                #fn_kind                                                       //     fn kind(&self) -> DrinkKind { ... }
+
+                /// Returns `true` if the variant is of the given kind.
+                pub fn is_kind(&self, kind: #kind_name) -> bool {              //     pub fn is_kind(&self, kind: DrinkKind) -> bool {
+                    self.#kind_method() == kind                                //         self.kind() == kind
+                }                                                              //     }
+
+                /// Consumes the value and returns its kind.
+                pub fn into_kind(self) -> #kind_name {                         //     pub fn into_kind(self) -> DrinkKind {
+                    self.#kind_method()                                        //         self.kind()
+                }                                                              //     }
+            }                                                                  // }
+
+            #impl_kinded_trait                                                 // impl<T> ::kinded::Kinded for Drink<T> { .. }
+        );
+    }
+
+    let impl_display_trait = gen_impl_display_trait(meta);
+    let impl_partial_eq_traits = gen_impl_partial_eq_traits(meta);
+    let impl_from_kind_trait = gen_impl_from_kind_trait(meta);
+    let impl_try_from_kind_trait = gen_impl_try_from_kind_trait(meta);
+
+    let fn_kind_index = gen_fn_kind_index(meta);
+
     quote!(
-        impl #generics #main_enum_with_generics {                              // impl<T> Drink<T> {
+        #[allow(dead_code)]
+        impl #impl_generics #main_enum_with_generics #where_clause {           // impl<T> Drink<T> {
             #fn_kind                                                           //     fn kind(&self) -> DrinkKind { ... }
+
+            /// Returns `true` if the variant is of the given kind.
+            pub fn is_kind(&self, kind: #kind_name) -> bool {                  //     pub fn is_kind(&self, kind: DrinkKind) -> bool {
+                self.#kind_method() == kind                                    //         self.kind() == kind
+            }                                                                  //     }
+
+            /// Consumes the value and returns its kind, handy in iterator adapters like `.map(Drink::into_kind)`.
+            pub fn into_kind(self) -> #kind_name {                             //     pub fn into_kind(self) -> DrinkKind {
+                self.#kind_method()                                            //         self.kind()
+            }                                                                  //     }
+
+            #fn_kind_index                                                     //     pub const fn kind_index(&self) -> usize { .. }
         }                                                                      // }
 
         #impl_kinded_trait                                                     // impl<T> ::kinded::Kinded for Drink<T> { .. }
+        #impl_display_trait                                                    // impl<T> core::fmt::Display for Drink<T> { .. }
+        #impl_partial_eq_traits                                                // impl<T> PartialEq<DrinkKind> for Drink<T> { .. }
+        #impl_from_kind_trait                                                  // impl From<DrinkKind> for Drink { .. }
+        #impl_try_from_kind_trait                                              // impl TryFrom<DrinkKind> for Drink { .. }
+    )
+}
+
+/// Without Copy the kind type has no `to_index()`, see `Meta::derives_copy()`.
+fn gen_fn_kind_index(meta: &Meta) -> TokenStream {
+    if !meta.derives_copy() {
+        return quote!();
+    }
+
+    let kind_method = meta.kind_method();
+
+    // kind() is const only when there are no generics, see gen_fn_kind()
+    let maybe_const = if meta.generics.params.is_empty() {
+        quote!(const)
+    } else {
+        quote!()
+    };
+
+    quote!(
+        /// Returns the position of the variant's kind in the declaration order,
+        /// the same as `self.kind().to_index()`.
+        pub #maybe_const fn kind_index(&self) -> usize {                       // pub const fn kind_index(&self) -> usize {
+            self.#kind_method().to_index()                                     //     self.kind().to_index()
+        }                                                                      // }
     )
 }
 
 fn gen_fn_kind(meta: &Meta) -> TokenStream {
     let name = &meta.ident;
-    let kind_name = meta.kind_name();
-    let match_branches = meta
-        .variants
-        .iter()
-        .map(|variant| gen_match_branch(name, &kind_name, variant));
+    let kind_name = meta.kind_path();
+    let kind_method = meta.kind_method();
+
+    let body = match meta.data_type {
+        DataType::Enum if meta.kinded_attrs.fast_kind.is_some() => {
+            let repr = &meta.repr;
+            // Sound, because `#[repr(Int)]` enums start with the tag of type `Int`, and the tags
+            // are the declaration indexes, as there are no explicit discriminants or skipped variants
+            // (see validate_fast_kind)
+            quote!(
+                let tag = unsafe { *(self as *const Self as *const #repr) };   // let tag = unsafe { *(self as *const Self as *const u8) };
+                #kind_name::INDEX_TO_KIND[tag as usize]                        // DrinkKind::INDEX_TO_KIND[tag as usize]
+            )
+        }
+        DataType::Enum => {
+            let match_branches = meta
+                .variants
+                .iter()
+                .map(|variant| gen_match_branch(meta, variant));
+
+            quote!(
+                match *self {                                                  // match *self {
+                    #(#match_branches),*                                       //     Drink::Coffee(..) => DrinkKind::Coffee,
+                }                                                              // }
+            )
+        }
+        DataType::Struct => {
+            // The only variant is named after the struct
+            let kind_variant_name = meta.kind_variant_ident(name);
+            quote!(
+                #kind_name::#kind_variant_name                                 // CoffeeKind::Coffee
+            )
+        }
+    };
+
+    // Keep it simple and make the function const only when there are no generics
+    let maybe_const = if meta.generics.params.is_empty() {
+        quote!(const)
+    } else {
+        quote!()
+    };
 
     quote!(
-        pub fn kind(&self) -> #kind_name {                                     // pub fn kind(&self) -> DrinkKind {
-            match self {                                                       //     match self {
-                #(#match_branches),*                                           //         Drink::Coffee(..) => DrinkKind::Coffee,
-            }                                                                  //     }
+        /// Returns the kind of the variant, without its data.
+        pub #maybe_const fn #kind_method(&self) -> #kind_name {                // pub const fn kind(&self) -> DrinkKind {
+            #body                                                              //     match *self { .. }
         }                                                                      // }
     )
 }
 
-fn gen_match_branch(name: &Ident, kind_name: &Ident, variant: &Variant) -> TokenStream {
+fn gen_match_branch(meta: &Meta, variant: &Variant) -> TokenStream {
+    let name = &meta.ident;
+    let kind_name = meta.kind_path();
     let variant_name = &variant.ident;
+    let cfgs = &variant.cfgs;
     let variant_destruct = match variant.fields_type {
         FieldsType::Named => quote!({ .. }),
         FieldsType::Unnamed => quote!((..)),
         FieldsType::Unit => quote!(),
     };
 
-    quote!(
-        #name::#variant_name #variant_destruct => #kind_name::#variant_name
-    )
+    if variant.kinded_attrs.skip {
+        let msg = format!("{name}::{variant_name} is skipped with #[kinded(skip)] and has no kind");
+        quote!(
+            #(#cfgs)* #name::#variant_name #variant_destruct => ::core::panic!(#msg)
+        )
+    } else {
+        let kind_variant_name = meta.kind_variant_ident(variant_name);
+        quote!(
+            #(#cfgs)* #name::#variant_name #variant_destruct => #kind_name::#kind_variant_name
+        )
+    }
 }
 
 fn gen_impl_kinded_trait(meta: &Meta) -> TokenStream {
-    let kind_name = meta.kind_name();
+    let kind_name = meta.kind_path();
+    let kind_method = meta.kind_method();
     let main_enum_with_generics = meta.main_enum_with_generics();
-    let generics = &meta.generics;
+    let (impl_generics, _, where_clause) = meta.generics.split_for_impl();
 
     quote!(
-        impl #generics ::kinded::Kinded for #main_enum_with_generics {         // impl<T> ::kinded::Kinded for Drink<T> {
+        #[automatically_derived]
+        impl #impl_generics ::kinded::Kinded for #main_enum_with_generics      // impl<T> ::kinded::Kinded for Drink<T>
+        #where_clause {                                                        // where .. {
             type Kind = #kind_name;                                            //     type Kind = DrinkKind;
                                                                                //
             fn kind(&self) -> #kind_name {                                     //     fn kind(&self) -> DrinkKind {
-                self.kind()                                                    //         self.kind()
+                self.#kind_method()                                            //         self.kind()
+            }                                                                  //     }
+        }                                                                      // }
+    )
+}
+
+fn gen_impl_display_trait(meta: &Meta) -> TokenStream {
+    if !meta.kinded_attrs.display_original {
+        return quote!();
+    }
+
+    let kind_method = meta.kind_method();
+    let main_enum_with_generics = meta.main_enum_with_generics();
+    let (impl_generics, _, where_clause) = meta.generics.split_for_impl();
+
+    quote!(
+        #[automatically_derived]
+        impl #impl_generics ::core::fmt::Display for #main_enum_with_generics       // impl<T> core::fmt::Display for Drink<T>
+        #where_clause {                                                             // where .. {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result { //     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                ::core::fmt::Display::fmt(&self.#kind_method(), f)                  //         core::fmt::Display::fmt(&self.kind(), f)
+            }                                                                       //     }
+        }                                                                           // }
+    )
+}
+
+fn gen_impl_partial_eq_traits(meta: &Meta) -> TokenStream {
+    if !meta.kinded_attrs.eq_original {
+        return quote!();
+    }
+
+    let kind_name = meta.kind_path();
+    let kind_method = meta.kind_method();
+    let main_enum_with_generics = meta.main_enum_with_generics();
+    let (impl_generics, _, where_clause) = meta.generics.split_for_impl();
+
+    quote!(
+        #[automatically_derived]
+        impl #impl_generics ::core::cmp::PartialEq<#kind_name> for #main_enum_with_generics // impl<T> PartialEq<DrinkKind> for Drink<T>
+        #where_clause {                                                        // where .. {
+            fn eq(&self, other: &#kind_name) -> bool {                         //     fn eq(&self, other: &DrinkKind) -> bool {
+                self.#kind_method() == *other                                  //         self.kind() == *other
+            }                                                                  //     }
+        }                                                                      // }
+
+        #[automatically_derived]
+        impl #impl_generics ::core::cmp::PartialEq<#main_enum_with_generics> for #kind_name // impl<T> PartialEq<Drink<T>> for DrinkKind
+        #where_clause {                                                        // where .. {
+            fn eq(&self, other: &#main_enum_with_generics) -> bool {           //     fn eq(&self, other: &Drink<T>) -> bool {
+                *self == other.#kind_method()                                  //         *self == other.kind()
+            }                                                                  //     }
+        }                                                                      // }
+    )
+}
+
+/// When all the variants are unit variants, the enum and its kind are isomorphic,
+/// so the kind can be converted back.
+fn gen_impl_from_kind_trait(meta: &Meta) -> TokenStream {
+    if !meta.has_only_unit_variants() {
+        return quote!();
+    }
+
+    let name = &meta.ident;
+    let kind_name = meta.kind_path();
+    let main_enum_with_generics = meta.main_enum_with_generics();
+    let (impl_generics, _, where_clause) = meta.generics.split_for_impl();
+
+    let body = match meta.data_type {
+        DataType::Enum => {
+            let match_branches = meta.kind_variants().map(|variant| {
+                let variant_name = &variant.ident;
+                let kind_variant_name = meta.kind_variant_ident(variant_name);
+                let cfgs = &variant.cfgs;
+                quote!(#(#cfgs)* #kind_name::#kind_variant_name => #name::#variant_name)
+            });
+            quote!(
+                match kind {                                                   // match kind {
+                    #(#match_branches),*                                       //     DrinkKind::Mate => Drink::Mate,
+                }                                                              // }
+            )
+        }
+        DataType::Struct => {
+            let kind_variant_name = meta.kind_variant_ident(name);
+            quote!(
+                let #kind_name::#kind_variant_name = kind;                     // let CoffeeKind::Coffee = kind;
+                #name                                                          // Coffee
+            )
+        }
+    };
+
+    quote!(
+        #[automatically_derived]
+        impl #impl_generics ::core::convert::From<#kind_name> for #main_enum_with_generics // impl From<DrinkKind> for Drink
+        #where_clause {                                                        // where .. {
+            fn from(kind: #kind_name) -> Self {                                //     fn from(kind: DrinkKind) -> Self {
+                #body                                                          //         match kind { .. }
+            }                                                                  //     }
+        }                                                                      // }
+    )
+}
+
+fn gen_impl_try_from_kind_trait(meta: &Meta) -> TokenStream {
+    // With only unit variants From<DrinkKind> is implemented, which already provides TryFrom
+    if !meta.kinded_attrs.try_into_original || meta.has_only_unit_variants() {
+        return quote!();
+    }
+
+    let name = &meta.ident;
+    let kind_name = meta.kind_path();
+    let main_enum_with_generics = meta.main_enum_with_generics();
+
+    // The probe resolves on the declared bounds, so a field of type `T` would never get its default,
+    // even for `T = i32`. Requiring `T: Default` makes the result the same as for a concrete type.
+    let mut generics = meta.generics.clone();
+    let type_params: Vec<&Ident> = meta
+        .generics
+        .type_params()
+        .map(|param| &param.ident)
+        .collect();
+    let mut default_params: Vec<&Ident> = Vec::new();
+    for field in meta.kind_variants().flat_map(|variant| &variant.fields) {
+        if let syn::Type::Path(ref type_path) = field.ty {
+            if let Some(param) = type_params
+                .iter()
+                .find(|param| type_path.qself.is_none() && type_path.path.is_ident(**param))
+            {
+                if !default_params.contains(param) {
+                    default_params.push(param);
+                }
+            }
+        }
+    }
+    let where_clause = generics.make_where_clause();
+    for param in default_params {
+        where_clause
+            .predicates
+            .push(syn::parse_quote!(#param: ::core::default::Default));
+    }
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+
+    let match_branches = meta.kind_variants().map(|variant| {
+        let variant_name = &variant.ident;
+        let kind_variant_name = meta.kind_variant_ident(variant_name);
+        let cfgs = &variant.cfgs;
+        let constructor = match meta.data_type {
+            DataType::Enum => quote!(#name::#variant_name),
+            DataType::Struct => quote!(#name),
+        };
+
+        let field_vars: Vec<Ident> = (0..variant.fields.len())
+            .map(|index| format_ident!("field_{index}"))
+            .collect();
+        let probes = variant.fields.iter().map(|field| {
+            let ty = &field.ty;
+            quote!(
+                (&::kinded::__private::DefaultProbe::<#ty>::new()).probe_default()
+            )
+        });
+        let value = match variant.fields_type {
+            FieldsType::Named => {
+                let field_names = variant.fields.iter().map(|field| &field.ident);
+                quote!(#constructor { #(#field_names: #field_vars),* })
+            }
+            FieldsType::Unnamed => quote!(#constructor(#(#field_vars),*)),
+            FieldsType::Unit => quote!(#constructor),
+        };
+
+        quote!(
+            #(#cfgs)*                                                          // #[cfg(..)]
+            #kind_name::#kind_variant_name => match (#(#probes,)*) {           // DrinkKind::Tea => match (probe::<String>(),) {
+                (#(::core::option::Option::Some(#field_vars),)*) => ::core::result::Result::Ok(#value), //     (Some(field_0),) => Ok(Drink::Tea { variety: field_0 }),
+                #[allow(unreachable_patterns)]                                 //
+                _ => ::core::result::Result::Err(kind),                        //     _ => Err(kind),
+            }                                                                  // }
+        )
+    });
+
+    quote!(
+        #[automatically_derived]
+        impl #impl_generics ::core::convert::TryFrom<#kind_name> for #main_enum_with_generics
+        #where_clause {                                                        // impl TryFrom<DrinkKind> for Drink {
+            type Error = #kind_name;                                           //     type Error = DrinkKind;
+                                                                               //
+            fn try_from(kind: #kind_name) -> ::core::result::Result<Self, Self::Error> {
+                #[allow(unused_imports)]
+                use ::kinded::__private::{ProbeDefault as _, ProbeNoDefault as _};
+
+                match kind {                                                   //         match kind {
+                    #(#match_branches),*                                       //             DrinkKind::Mate => Ok(Drink::Mate), ..
+                }                                                              //         }
             }                                                                  //     }
         }                                                                      // }
     )
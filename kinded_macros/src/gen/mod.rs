@@ -1,14 +1,47 @@
+mod histogram;
+mod iter;
 mod kind_enum;
 mod main_enum;
+mod set;
 
 use crate::models::Meta;
 use proc_macro2::TokenStream;
 use quote::quote;
 
+/// Generate the kind type and the extra items of the main enum.
+/// Trait implementations are marked with `#[automatically_derived]` and the rest with
+/// `#[allow(dead_code)]`, so lints users can't address are not reported in the generated code.
 pub fn generate(meta: Meta) -> TokenStream {
     let kind_enum = kind_enum::gen_kind_enum(&meta);
+    let iter = iter::gen_iter(&meta);
+    let histogram = histogram::gen_histogram(&meta);
+    let set = set::gen_set(&meta);
     let main_enum_extra = main_enum::gen_main_enum_extra(&meta);
 
+    let kind_enum = match meta.kinded_attrs.module {
+        Some(ref module) => {
+            let vis = meta.module_vis();
+            let doc = format!("Kind type of [`{}`].", meta.ident);
+            quote!(
+                #[doc = #doc]
+                #vis mod #module {
+                    use super::*;
+
+                    #kind_enum
+                    #iter
+                    #histogram
+                    #set
+                }
+            )
+        }
+        None => quote!(
+            #kind_enum
+            #iter
+            #histogram
+            #set
+        ),
+    };
+
     quote!(
         #kind_enum
         #main_enum_extra
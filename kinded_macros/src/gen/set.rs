@@ -0,0 +1,122 @@
+use crate::models::Meta;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Generate a set type keeping the kinds as bits of `u64` indexed by `to_index()`,
+/// as an alternative to `HashSet<DrinkKind>` without hashing and allocation.
+pub fn gen_set(meta: &Meta) -> TokenStream {
+    if !meta.kinded_attrs.set {
+        return quote!();
+    }
+
+    let vis = meta.kind_vis();
+    let kind_name = meta.kind_name();
+    let set_name = meta.set_name();
+    let doc = format!("Set of [`{kind_name}`] variants, stored as bits of `u64`.");
+    // The number of kinds is known only after the cfgs are resolved, so it's checked at compile time
+    let too_many_msg = format!("{set_name} can hold at most 64 kinds");
+
+    quote!(
+        #[doc = #doc]                                                          // /// Set of [`DrinkKind`] variants, stored as bits of `u64`.
+        #[derive(                                                              // #[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+            ::core::clone::Clone,
+            ::core::marker::Copy,
+            ::core::cmp::PartialEq,
+            ::core::cmp::Eq,
+            ::core::hash::Hash,
+            ::core::default::Default,
+        )]
+        #[allow(dead_code)]
+        #vis struct #set_name {                                                // pub struct DrinkKindSet {
+            bits: u64,                                                         //     bits: u64,
+        }                                                                      // }
+
+        const _: () = ::core::assert!(#kind_name::COUNT <= 64, #too_many_msg); // const _: () = assert!(DrinkKind::COUNT <= 64, "..");
+
+        #[allow(dead_code)]
+        impl #set_name {                                                       // impl DrinkKindSet {
+            /// Creates an empty set.
+            pub const fn new() -> Self {                                       //     pub const fn new() -> Self {
+                Self { bits: 0 }                                               //         Self { bits: 0 }
+            }                                                                  //     }
+
+            /// Returns the bit of the kind.
+            const fn bit(kind: #kind_name) -> u64 {                            //     const fn bit(kind: DrinkKind) -> u64 {
+                1 << kind.to_index()                                           //         1 << kind.to_index()
+            }                                                                  //     }
+
+            /// Adds the kind to the set. Returns whether the kind was newly inserted.
+            pub fn insert(&mut self, kind: #kind_name) -> bool {               //     pub fn insert(&mut self, kind: DrinkKind) -> bool {
+                let is_new = !self.contains(kind);                             //         let is_new = !self.contains(kind);
+                self.bits |= Self::bit(kind);                                  //         self.bits |= Self::bit(kind);
+                is_new                                                         //         is_new
+            }                                                                  //     }
+
+            /// Removes the kind from the set. Returns whether the kind was present in the set.
+            pub fn remove(&mut self, kind: #kind_name) -> bool {               //     pub fn remove(&mut self, kind: DrinkKind) -> bool {
+                let was_present = self.contains(kind);                         //         let was_present = self.contains(kind);
+                self.bits &= !Self::bit(kind);                                 //         self.bits &= !Self::bit(kind);
+                was_present                                                    //         was_present
+            }                                                                  //     }
+
+            /// Returns `true` if the set contains the kind.
+            pub const fn contains(&self, kind: #kind_name) -> bool {           //     pub const fn contains(&self, kind: DrinkKind) -> bool {
+                self.bits & Self::bit(kind) != 0                               //         self.bits & Self::bit(kind) != 0
+            }                                                                  //     }
+
+            /// Returns the kinds which are in `self`, `other` or both.
+            pub const fn union(self, other: Self) -> Self {                    //     pub const fn union(self, other: Self) -> Self {
+                Self { bits: self.bits | other.bits }                          //         Self { bits: self.bits | other.bits }
+            }                                                                  //     }
+
+            /// Returns the kinds which are both in `self` and `other`.
+            pub const fn intersection(self, other: Self) -> Self {             //     pub const fn intersection(self, other: Self) -> Self {
+                Self { bits: self.bits & other.bits }                          //         Self { bits: self.bits & other.bits }
+            }                                                                  //     }
+
+            /// Returns the number of kinds in the set.
+            pub const fn len(&self) -> usize {                                 //     pub const fn len(&self) -> usize {
+                self.bits.count_ones() as usize                                //         self.bits.count_ones() as usize
+            }                                                                  //     }
+
+            /// Returns `true` if the set contains no kinds.
+            pub const fn is_empty(&self) -> bool {                             //     pub const fn is_empty(&self) -> bool {
+                self.bits == 0                                                 //         self.bits == 0
+            }                                                                  //     }
+
+            /// Returns an iterator over the kinds in the set in the order of `all()`.
+            pub fn iter(&self) -> impl ::core::iter::Iterator<Item = #kind_name> { // pub fn iter(&self) -> impl Iterator<Item = DrinkKind> {
+                let set = *self;                                               //         let set = *self;
+                #kind_name::all()                                              //         DrinkKind::all()
+                    .iter()                                                    //             .iter()
+                    .copied()                                                  //             .copied()
+                    .filter(move |kind| set.contains(*kind))                   //             .filter(move |kind| set.contains(*kind))
+            }                                                                  //     }
+        }                                                                      // }
+
+        #[automatically_derived]
+        impl ::core::fmt::Debug for #set_name {                                // impl Debug for DrinkKindSet {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result { // fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                f.debug_set().entries(self.iter()).finish()                    //         f.debug_set().entries(self.iter()).finish()
+            }                                                                  //     }
+        }                                                                      // }
+
+        #[automatically_derived]
+        impl ::core::iter::Extend<#kind_name> for #set_name {                  // impl Extend<DrinkKind> for DrinkKindSet {
+            fn extend<I: ::core::iter::IntoIterator<Item = #kind_name>>(&mut self, iter: I) { // fn extend<I: IntoIterator<Item = DrinkKind>>(&mut self, iter: I) {
+                for kind in iter {                                             //         for kind in iter {
+                    self.insert(kind);                                         //             self.insert(kind);
+                }                                                              //         }
+            }                                                                  //     }
+        }                                                                      // }
+
+        #[automatically_derived]
+        impl ::core::iter::FromIterator<#kind_name> for #set_name {            // impl FromIterator<DrinkKind> for DrinkKindSet {
+            fn from_iter<I: ::core::iter::IntoIterator<Item = #kind_name>>(iter: I) -> Self { // fn from_iter<I: IntoIterator<Item = DrinkKind>>(iter: I) -> Self {
+                let mut set = Self::new();                                     //         let mut set = Self::new();
+                set.extend(iter);                                              //         set.extend(iter);
+                set                                                            //         set
+            }                                                                  //     }
+        }                                                                      // }
+    )
+}
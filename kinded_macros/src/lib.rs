@@ -25,5 +25,10 @@ fn expand_derive(input: proc_macro::TokenStream) -> Result<TokenStream, syn::Err
     let derive_input: DeriveInput =
         syn::parse(input).expect("kinded failed parse token stream as DeriveInput");
     let meta = parse::parse_derive_input(derive_input)?;
-    Ok(gen::generate(meta))
+    let debug = meta.kinded_attrs.debug;
+    let output = gen::generate(meta);
+    if debug {
+        eprintln!("{output}");
+    }
+    Ok(output)
 }
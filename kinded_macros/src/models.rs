@@ -1,17 +1,35 @@
-use proc_macro2::{Ident, TokenStream};
+use proc_macro2::{Ident, Span, TokenStream};
 use quote::{format_ident, quote};
-use syn::{Generics, Path, Visibility};
+use syn::{ext::IdentExt, parse_quote, Attribute, Generics, Path, Visibility};
+
+/// Traits derived for the kind type by default.
+pub const DEFAULT_DERIVE_TRAITS: &[&str] = &["Debug", "Clone", "Copy", "PartialEq", "Eq", "Hash"];
+
+/// Default traits required by the `Kind` trait and the generated code.
+/// They can't be removed with `no_derive(..)`. `Copy` can be removed, but then the methods taking
+/// the kind by value are not generated, see `Meta::derives_copy()`.
+pub const REQUIRED_DERIVE_TRAITS: &[&str] = &["Debug", "Clone", "PartialEq", "Eq"];
 
 #[derive(Debug)]
 pub struct Meta {
     /// Visibility of enum.
-    /// Kind implementation inherits this visibility automatically.
+    /// Kind implementation inherits this visibility, unless `vis = ...` is specified.
     pub vis: Visibility,
 
     pub ident: Ident,
 
     pub generics: Generics,
 
+    /// Whether the enum is marked with `#[non_exhaustive]`.
+    pub non_exhaustive: bool,
+
+    /// Integer representation of the enum, given with `#[repr(..)]`.
+    pub repr: Option<Ident>,
+
+    /// Whether the kind is derived on an enum or on a struct.
+    pub data_type: DataType,
+
+    /// Variants of the enum. A struct is represented by a single variant named after the struct.
     pub variants: Vec<Variant>,
 
     /// Attributes specified with #[kinded(..)] above the enum definition.
@@ -20,47 +38,425 @@ pub struct Meta {
 
 impl Meta {
     /// Get the name for the kind type.
+    /// `kind = ...` takes precedence over `prefix = ...` and `suffix = ...`.
     pub fn kind_name(&self) -> Ident {
         if let Some(ref kind_name) = self.kinded_attrs.kind {
-            kind_name.clone()
-        } else {
-            format_ident!("{}Kind", self.ident)
+            return kind_name.clone();
         }
+        format_ident!("{}", self.affixed_kind_name())
+    }
+
+    /// Get the name for the kind type built from `prefix = ...` and `suffix = ...`,
+    /// which is checked to be a valid identifier in `validate_kind_name()`.
+    pub fn affixed_kind_name(&self) -> String {
+        let prefix = match self.kinded_attrs.prefix {
+            Some((ref prefix, _)) => prefix.as_str(),
+            None => "",
+        };
+        // With only a prefix given, the default "Kind" suffix is dropped
+        let suffix = match (&self.kinded_attrs.prefix, &self.kinded_attrs.suffix) {
+            (_, Some((suffix, _))) => suffix.as_str(),
+            (Some(_), None) => "",
+            (None, None) => "Kind",
+        };
+        format!("{prefix}{}{suffix}", self.ident)
+    }
+
+    /// Get the identifier of a kind variant, e.g. `KMate` for `Mate` with `variant_prefix = "K"`.
+    pub fn kind_variant_ident(&self, ident: &Ident) -> Ident {
+        match self.kinded_attrs.variant_prefix {
+            Some(ref prefix) => format_ident!("{prefix}{ident}", span = ident.span()),
+            None => ident.clone(),
+        }
+    }
+
+    /// Get the name of the histogram type generated with `histogram`, e.g. `DrinkKindHistogram`.
+    pub fn histogram_name(&self) -> Ident {
+        format_ident!("{}Histogram", self.kind_name())
+    }
+
+    /// Get the name of the iterator type returned by `iter()`, e.g. `DrinkKindIter`.
+    pub fn iter_name(&self) -> Ident {
+        format_ident!("{}Iter", self.kind_name())
+    }
+
+    /// Get the name of the set type generated with `set`, e.g. `DrinkKindSet`.
+    pub fn set_name(&self) -> Ident {
+        format_ident!("{}Set", self.kind_name())
+    }
+
+    /// Get the name of the inherent method returning the kind, `kind` unless `method = ...` is specified.
+    pub fn kind_method(&self) -> Ident {
+        match self.kinded_attrs.method {
+            Some(ref method) => method.clone(),
+            None => format_ident!("kind"),
+        }
+    }
+
+    /// Get the path to the kind type from the main enum, e.g. `kinds::DrinkKind`
+    /// when the kind type is generated in a module.
+    pub fn kind_path(&self) -> TokenStream {
+        let kind_name = self.kind_name();
+        match self.kinded_attrs.module {
+            Some(ref module) => quote!(#module::#kind_name),
+            None => quote!(#kind_name),
+        }
+    }
+
+    /// Get the visibility of the module with the kind type.
+    /// It's the visibility the kind type would have without the module.
+    pub fn module_vis(&self) -> &Visibility {
+        self.kinded_attrs.vis.as_ref().unwrap_or(&self.vis)
+    }
+
+    /// Get the visibility of the kind type.
+    /// Inside a module the visibility is shifted one level up, so the kind type
+    /// is reachable from the same places as the module.
+    pub fn kind_vis(&self) -> Visibility {
+        let vis = self.module_vis();
+        if self.kinded_attrs.module.is_none() {
+            return vis.clone();
+        }
+        match vis {
+            Visibility::Inherited => parse_quote!(pub(super)),
+            Visibility::Restricted(restricted) if restricted.path.is_ident("self") => {
+                parse_quote!(pub(super))
+            }
+            Visibility::Restricted(restricted)
+                if restricted
+                    .path
+                    .segments
+                    .first()
+                    .is_some_and(|segment| segment.ident == "super") =>
+            {
+                let path = &restricted.path;
+                parse_quote!(pub(in super::#path))
+            }
+            _ => vis.clone(),
+        }
+    }
+
+    /// Whether the kind type must be marked with #[non_exhaustive].
+    /// It's inherited from the enum or can be specified explicitly.
+    pub fn kind_non_exhaustive(&self) -> bool {
+        self.non_exhaustive || self.kinded_attrs.non_exhaustive
+    }
+
+    /// Get the integer representation of the kind type.
+    /// When the variants have explicit discriminants, it's inherited from the enum,
+    /// unless `repr = ...` is specified.
+    pub fn kind_repr(&self) -> Option<&Ident> {
+        self.kinded_attrs.repr.as_ref().or_else(|| {
+            if self.has_discriminants() {
+                self.repr.as_ref()
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Whether any variant of the enum has an explicit discriminant.
+    pub fn has_discriminants(&self) -> bool {
+        self.variants
+            .iter()
+            .any(|variant| variant.discriminant.is_some())
+    }
+
+    /// Get the discriminants of the kind variants, so they match the discriminants of the enum.
+    /// Implicit discriminants are computed from the previous explicit one, because skipped
+    /// variants would otherwise shift them.
+    pub fn kind_discriminants(&self) -> Vec<Option<TokenStream>> {
+        if !self.has_discriminants() {
+            return self.kind_variants().map(|_| None).collect();
+        }
+        // Which variants are removed by `#[cfg(..)]` is known only to the compiler
+        if self.variants.iter().any(|variant| !variant.cfgs.is_empty()) {
+            return self.cfg_aware_kind_discriminants();
+        }
+
+        let mut discriminants = Vec::new();
+        let mut last_explicit: Option<&syn::Expr> = None;
+        let mut offset: usize = 0;
+        for variant in &self.variants {
+            let discriminant = match variant.discriminant {
+                Some(ref expr) => {
+                    last_explicit = Some(expr);
+                    offset = 0;
+                    quote!(#expr)
+                }
+                None => {
+                    let offset_lit = proc_macro2::Literal::usize_unsuffixed(offset);
+                    match last_explicit {
+                        Some(expr) => quote!((#expr) + #offset_lit),
+                        None => quote!(#offset_lit),
+                    }
+                }
+            };
+            if !variant.kinded_attrs.skip {
+                discriminants.push(Some(discriminant));
+            }
+            offset += 1;
+        }
+        discriminants
+    }
+
+    /// Same as `kind_discriminants()`, but an implicit discriminant is a block going through
+    /// the previous variants with their cfgs, so the variants removed by `#[cfg(..)]` don't count, e.g.
+    /// `{ let mut next = 0; next = (10) + 1; #[cfg(feature = "x")] { next += 1; } next }`.
+    fn cfg_aware_kind_discriminants(&self) -> Vec<Option<TokenStream>> {
+        let mut discriminants = Vec::new();
+        // Statements computing the implicit discriminant of the next variant
+        let mut steps: Vec<TokenStream> = Vec::new();
+        for variant in &self.variants {
+            let discriminant = match variant.discriminant {
+                Some(ref expr) => quote!(#expr),
+                None => quote!({
+                    #[allow(unused_mut)]
+                    let mut next = 0;
+                    #(#steps)*
+                    next
+                }),
+            };
+            if !variant.kinded_attrs.skip {
+                discriminants.push(Some(discriminant));
+            }
+
+            let cfgs = &variant.cfgs;
+            let step = match variant.discriminant {
+                Some(ref expr) => quote!(next = (#expr) + 1;),
+                None => quote!(next += 1;),
+            };
+            steps.push(if cfgs.is_empty() {
+                step
+            } else {
+                quote!(#(#cfgs)* { #step })
+            });
+        }
+        discriminants
     }
 
     /// Get the traits that need to be derived.
+    /// `Hash` is implemented manually instead of derived, unless the kind is not `Copy`, see `hashes_kind()`.
     pub fn derive_traits(&self) -> Vec<Path> {
-        const DEFAULT_DERIVE_TRAITS: &[&str] = &["Debug", "Clone", "Copy", "PartialEq", "Eq"];
-
-        let mut traits: Vec<Path> = DEFAULT_DERIVE_TRAITS
+        // The default traits are fully qualified, so they can't be shadowed by local items
+        let default_traits: Vec<Path> = DEFAULT_DERIVE_TRAITS
             .iter()
-            .map(|trait_name| Path::from(format_ident!("{trait_name}")))
+            .filter(|default_name| !self.is_removed_trait(default_name))
+            .map(|trait_name| default_trait_path(trait_name))
             .collect();
 
-        // Add the extra specified traits, if they're different from the default ones
-        if let Some(ref extra_traits) = self.kinded_attrs.derive {
-            for extra_trait in extra_traits {
-                if !traits.contains(extra_trait) {
-                    traits.push(extra_trait.clone());
-                }
+        // Add the extra specified traits, if they're different from the default ones.
+        // Traits are compared by the last path segment, so `core::fmt::Debug` is recognized as `Debug`
+        let mut extra_traits: Vec<Path> = Vec::new();
+        for extra_trait in self.kinded_attrs.derive.iter().flatten() {
+            let is_redundant = default_traits
+                .iter()
+                .chain(&extra_traits)
+                .any(|known| trait_name(known) == trait_name(extra_trait));
+            if !is_redundant {
+                extra_traits.push(extra_trait.clone());
+            }
+        }
+
+        default_traits
+            .into_iter()
+            .filter(|default_trait| {
+                trait_name(default_trait).is_some_and(|name| name != "Hash" || !self.hashes_kind())
+            })
+            .chain(extra_traits)
+            .collect()
+    }
+
+    /// Whether the default trait is removed with `no_derive(..)`.
+    fn is_removed_trait(&self, default_name: &str) -> bool {
+        self.kinded_attrs
+            .no_derive
+            .iter()
+            .flatten()
+            .filter_map(trait_name)
+            .any(|name| name == default_name)
+    }
+
+    /// Whether `Hash` is implemented for the kind type. Instead of the derived implementation,
+    /// which hashes the discriminant, it hashes the stable id or the index in the declaration order.
+    pub fn hashes_kind(&self) -> bool {
+        !self.is_removed_trait("Hash") && self.derives_copy()
+    }
+
+    /// Whether the kind type is `Copy`. With `no_derive(Copy)` the methods taking the kind by value
+    /// or copying it out of the constants, e.g. `to_index()` or `from_index()`, are not generated.
+    /// The trait implementations, `all()`, `iter()` and `as_str()` only need `Clone` and are kept.
+    pub fn derives_copy(&self) -> bool {
+        !self.is_removed_trait("Copy")
+    }
+
+    /// Get the variants that make it into the kind type.
+    pub fn kind_variants(&self) -> impl Iterator<Item = &Variant> {
+        self.variants
+            .iter()
+            .filter(|variant| !variant.kinded_attrs.skip)
+    }
+
+    /// Get the kind variants in the order of `all()`, which is the declaration order
+    /// unless `all_order = "name"` sorts them by their display names.
+    pub fn all_kind_variants(&self) -> Vec<&Variant> {
+        let mut variants: Vec<&Variant> = self.kind_variants().collect();
+        if let Some(AllOrder::Name) = self.kinded_attrs.all_order {
+            variants.sort_by_cached_key(|variant| self.display_name(variant));
+        }
+        variants
+    }
+
+    /// Get the string the kind variant is displayed as.
+    /// `rename` of the variant wins over its `display`, which wins over `display` of the enum.
+    pub fn display_name(&self, variant: &Variant) -> String {
+        if let Some(ref rename) = variant.kinded_attrs.rename {
+            return rename.clone();
+        }
+
+        let original = variant.ident.to_string();
+        if let Some(display_case) = variant.kinded_attrs.display {
+            return display_case.apply(&original);
+        }
+        // The separator customizes only the display case of the enum, see validate_separator()
+        match (self.kinded_attrs.display, &self.kinded_attrs.separator) {
+            // The case of `#[serde(rename_all = "...")]` must give the same names as serde
+            (Some(display_case), _) if self.kinded_attrs.display_serde => {
+                display_case.apply_serde(&original)
+            }
+            (Some(display_case), Some((separator, _))) => {
+                display_case.apply_with_separator(&original, separator)
             }
+            (Some(display_case), None) => display_case.apply(&original),
+            (None, _) => original,
         }
+    }
+
+    /// Get the name of the predicate checking for the kind variant, e.g. `is_hot_mate` for `HotMate`.
+    pub fn predicate_name(&self, variant: &Variant) -> Ident {
+        let name = DisplayCase::Snake.apply(&variant.ident.unraw().to_string());
+        format_ident!("is_{name}", span = variant.ident.span())
+    }
+
+    /// Whether no other kind variant gets the same `predicate_name()`, e.g. `HttpServer` and `HTTPServer` both
+    /// map to `is_http_server`. Colliding variants get neither a predicate nor an index constant.
+    pub fn has_unique_predicate_name(&self, variant: &Variant) -> bool {
+        let predicate_name = self.predicate_name(variant);
+        self.kind_variants()
+            .filter(|other| self.predicate_name(other) == predicate_name)
+            .count()
+            == 1
+    }
 
-        traits
+    /// Get the name of the constant with the index of the kind variant, e.g. `HOT_MATE_INDEX` for `HotMate`.
+    /// It's derived from the same words as `predicate_name()`, so it collides exactly when the predicate does.
+    pub fn index_const_name(&self, variant: &Variant) -> Ident {
+        let name = DisplayCase::ScreamingSnake.apply(&variant.ident.unraw().to_string());
+        format_ident!("{name}_INDEX", span = variant.ident.span())
+    }
+
+    /// Whether all the variants are unit variants, so the enum can be built from its kind.
+    pub fn has_only_unit_variants(&self) -> bool {
+        self.variants
+            .iter()
+            .all(|variant| matches!(variant.fields_type, FieldsType::Unit))
+    }
+
+    /// Whether stable ids are given to the kind variants with `id = ...`.
+    pub fn has_stable_ids(&self) -> bool {
+        self.kind_variants()
+            .any(|variant| variant.kinded_attrs.id.is_some())
+    }
+
+    /// Whether any of the kind variants is conditionally compiled with `#[cfg(..)]`.
+    pub fn has_cfg_variants(&self) -> bool {
+        self.kind_variants().any(|variant| !variant.cfgs.is_empty())
+    }
+
+    /// Whether any display case is specified, either for the enum or for a variant.
+    pub fn has_custom_display(&self) -> bool {
+        self.kinded_attrs.display.is_some()
+            || self
+                .variants
+                .iter()
+                .any(|variant| variant.kinded_attrs.display.is_some())
     }
 
     pub fn main_enum_with_generics(&self) -> TokenStream {
         let type_name = &self.ident;
-        let generics = &self.generics;
+        let (_, type_generics, _) = self.generics.split_for_impl();
 
-        quote!(#type_name #generics)
+        quote!(#type_name #type_generics)
     }
 }
 
+/// Order of the kinds returned by `all()`, specified with `all_order = "..."`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AllOrder {
+    /// The order of the variant declarations
+    Declaration,
+    /// Sorted by the display names
+    Name,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DataType {
+    Enum,
+    Struct,
+}
+
+/// Fully qualified path of a default trait, e.g. `::core::fmt::Debug` for `Debug`.
+fn default_trait_path(trait_name: &str) -> Path {
+    let module = match trait_name {
+        "Debug" => "fmt",
+        "Clone" => "clone",
+        "Copy" => "marker",
+        "PartialEq" | "Eq" => "cmp",
+        "Hash" => "hash",
+        _ => unreachable!("Unexpected default trait: {trait_name}"),
+    };
+    let module = format_ident!("{module}");
+    let trait_ident = format_ident!("{trait_name}");
+    parse_quote!(::core::#module::#trait_ident)
+}
+
+/// Lowercase every char of the string, the same way as generated code does at runtime with
+/// `char::to_lowercase`. Unlike `str::to_lowercase`, it doesn't special-case the final sigma.
+pub fn to_lowercase(s: &str) -> String {
+    s.chars().flat_map(char::to_lowercase).collect()
+}
+
+/// Name of a trait given by its path, e.g. `Debug` for `core::fmt::Debug`.
+pub fn trait_name(path: &Path) -> Option<&Ident> {
+    path.segments.last().map(|segment| &segment.ident)
+}
+
 #[derive(Debug)]
 pub struct Variant {
     pub ident: Ident,
     pub fields_type: FieldsType,
+
+    /// Number of fields of the variant.
+    pub fields_count: usize,
+
+    /// Fields of the variant, used to construct the variant from its kind.
+    pub fields: Vec<syn::Field>,
+
+    /// Explicit discriminant of the variant, e.g. `404` in `NotFound = 404`.
+    pub discriminant: Option<syn::Expr>,
+
+    /// Doc attributes of the variant, forwarded to the kind variant.
+    pub docs: Vec<Attribute>,
+
+    /// `#[cfg(..)]` attributes of the variant, forwarded to everything generated for the variant.
+    pub cfgs: Vec<Attribute>,
+
+    /// Whether the variant is marked with `#[doc(hidden)]`. The attribute is forwarded to the kind variant
+    /// with the other doc attributes, and added to the other items generated for the variant.
+    pub doc_hidden: bool,
+
+    /// Attributes specified with #[kinded(..)] above the variant.
+    pub kinded_attrs: VariantAttributes,
 }
 
 /// This mimics syn::Fields, but without payload.
@@ -82,11 +478,121 @@ pub struct KindedAttributes {
     /// Name for the kind type, specified with `kind = ...`
     pub kind: Option<Ident>,
 
+    /// Prefix for the generated kind type name, specified with `prefix = "..."`
+    pub prefix: Option<(String, Span)>,
+
+    /// Suffix for the generated kind type name, specified with `suffix = "..."`
+    pub suffix: Option<(String, Span)>,
+
+    /// Prefix for the kind variant names, specified with `variant_prefix = "..."`
+    pub variant_prefix: Option<String>,
+
+    /// Order of the kinds returned by `all()`, specified with `all_order = "..."`
+    pub all_order: Option<AllOrder>,
+
     /// Traits to derive, specified with `derive(...)`
     pub derive: Option<Vec<Path>>,
 
+    /// Default traits not to derive, specified with `no_derive(...)`
+    pub no_derive: Option<Vec<Path>>,
+
+    /// Attributes to put on the kind type, specified with `attr(...)`
+    pub attrs: Option<Vec<syn::Meta>>,
+
     /// Attributes to customize implementation for Display trait
     pub display: Option<DisplayCase>,
+
+    /// Separator of the words in the display case, specified with `separator = "..."`
+    pub separator: Option<(String, Span)>,
+
+    /// Mirror `#[serde(rename_all = "...")]` and `#[serde(rename = "...")]` in Display trait,
+    /// specified with `display = "serde"`
+    pub display_serde: bool,
+
+    /// Implement Display trait for the main enum by delegating to the kind, specified with `display_original`
+    pub display_original: bool,
+
+    /// Implement TryFrom<DrinkKind> for the main enum, specified with `try_into_original`
+    pub try_into_original: bool,
+
+    /// Implement PartialEq between the main enum and the kind, specified with `eq_original`
+    pub eq_original: bool,
+
+    /// Make FromStr ignore ASCII case of the display names, specified with `parse_case_insensitive`
+    pub parse_case_insensitive: bool,
+
+    /// Do not generate implementation of FromStr trait, specified with `no_from_str`
+    pub no_from_str: bool,
+
+    /// Implement Serialize and Deserialize traits using the display names, specified with `serde`
+    pub serde: bool,
+
+    /// Implement Serialize and Deserialize traits using the declaration indexes, specified with `serde_repr`
+    pub serde_repr: bool,
+
+    /// Implement clap's ValueEnum trait using the display names, specified with `clap`
+    pub clap: bool,
+
+    /// Implement strum's traits and conversions, specified with `strum`
+    pub strum: bool,
+
+    /// Representation of the kind type, specified with `repr = ...`
+    pub repr: Option<Ident>,
+
+    /// Visibility of the kind type, specified with `vis = ...`
+    pub vis: Option<Visibility>,
+
+    /// Name of the inherent method returning the kind, specified with `method = ...`
+    pub method: Option<Ident>,
+
+    /// Module to generate the kind type in, specified with `module = ...`
+    pub module: Option<Ident>,
+
+    /// Variant returned by Default implementation, specified with `default = ...`
+    pub default: Option<Ident>,
+
+    /// Mark the kind type with #[non_exhaustive], specified with `non_exhaustive`
+    pub non_exhaustive: bool,
+
+    /// Generate a histogram type counting the kinds, specified with `histogram`
+    pub histogram: bool,
+
+    /// Generate a bit set type of the kinds, specified with `set`
+    pub set: bool,
+
+    /// Implement Error trait for the kind type, specified with `error`
+    pub error: bool,
+
+    /// Compute the kind from the tag of a `#[repr(Int)]` enum instead of a match, specified with `fast_kind`
+    pub fast_kind: Option<Span>,
+
+    /// Mark the kind type with #[must_use], specified with `must_use`
+    pub must_use: bool,
+
+    /// Print the generated code at compile time, specified with `debug`
+    pub debug: bool,
+
+    /// Map to the existing kind type given with `kind = ...` instead of defining one, specified with `external`
+    pub external: Option<Span>,
+}
+
+/// Attributes specified with #[kinded(..)] on a variant
+#[derive(Debug, Default)]
+pub struct VariantAttributes {
+    /// Exclude the variant from the kind type, specified with `skip`
+    pub skip: bool,
+
+    /// Use the variant as the default kind, specified with `default`
+    pub default: bool,
+
+    /// Stable id of the kind, independent of the declaration order, specified with `id = ...`
+    pub id: Option<(u32, Span)>,
+
+    /// Custom display name, specified with `rename = "..."`
+    pub rename: Option<String>,
+
+    /// Display case of the variant, overriding the one of the enum, specified with `display = "..."`
+    pub display: Option<DisplayCase>,
 }
 
 /// This uses the same names as serde + "Title Case" variant.
@@ -114,6 +620,9 @@ pub enum DisplayCase {
     /// Title Case
     Title,
 
+    /// Train-Case
+    Train,
+
     /// lowercase
     Lower,
 
@@ -134,6 +643,7 @@ impl From<DisplayCase> for convert_case::Case {
             DisplayCase::Kebab => Case::Kebab,
             DisplayCase::ScreamingKebab => Case::Cobol,
             DisplayCase::Title => Case::Title,
+            DisplayCase::Train => Case::Train,
             DisplayCase::Lower => Case::Flat,
             DisplayCase::Upper => Case::UpperFlat,
         }
@@ -151,6 +661,7 @@ impl DisplayCase {
             Kebab,
             ScreamingKebab,
             Title,
+            Train,
             Lower,
             Upper,
         ]
@@ -162,4 +673,58 @@ impl DisplayCase {
         let case: Case = self.into();
         s.to_case(case)
     }
+
+    /// Apply the case the way serde's `rename_all` does, which is not the same as `apply()`:
+    /// a new word starts only before an uppercase letter, so digits stay attached to the previous word
+    /// and every letter of an acronym is a word, e.g. `Http2` -> `http2`, `HTTPServer` -> `h_t_t_p_server`.
+    pub fn apply_serde(self, s: &str) -> String {
+        use DisplayCase::*;
+        let snake = || {
+            let mut snake = String::new();
+            for (index, ch) in s.char_indices() {
+                if index > 0 && ch.is_uppercase() {
+                    snake.push('_');
+                }
+                snake.push(ch.to_ascii_lowercase());
+            }
+            snake
+        };
+        match self {
+            Lower => s.to_ascii_lowercase(),
+            Upper => s.to_ascii_uppercase(),
+            Pascal => s.to_owned(),
+            Camel => {
+                let mut chars = s.chars();
+                match chars.next() {
+                    Some(first) => first.to_ascii_lowercase().to_string() + chars.as_str(),
+                    None => String::new(),
+                }
+            }
+            Snake => snake(),
+            ScreamingSnake => snake().to_ascii_uppercase(),
+            Kebab => snake().replace('_', "-"),
+            ScreamingKebab => snake().to_ascii_uppercase().replace('_', "-"),
+            // serde has no such cases, it rejects them itself
+            Title | Train => self.apply(s),
+        }
+    }
+
+    /// Apply the case, joining the words with the given separator instead of the one of the case.
+    pub fn apply_with_separator(self, s: &str, separator: &str) -> String {
+        use convert_case::{Case, Converter};
+        let case: Case = self.into();
+        Converter::new()
+            .to_case(case)
+            .set_delim(separator)
+            .convert(s)
+    }
+
+    /// Whether the case separates the words, so the separator can be customized with `separator = "..."`.
+    pub fn separates_words(self) -> bool {
+        use DisplayCase::*;
+        match self {
+            Snake | ScreamingSnake | Kebab | ScreamingKebab | Title | Train => true,
+            Camel | Pascal | Lower | Upper => false,
+        }
+    }
 }
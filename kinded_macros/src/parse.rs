@@ -1,45 +1,492 @@
-use crate::models::{DisplayCase, FieldsType, KindedAttributes, Meta, Variant};
-use proc_macro2::Ident;
-use quote::ToTokens;
+use crate::models::{
+    to_lowercase, trait_name, AllOrder, DataType, DisplayCase, FieldsType, KindedAttributes, Meta,
+    Variant, VariantAttributes, DEFAULT_DERIVE_TRAITS, REQUIRED_DERIVE_TRAITS,
+};
+use proc_macro2::{Ident, TokenStream, TokenTree};
+use quote::quote;
 use syn::{
     bracketed, parenthesized,
-    parse::{Parse, ParseStream},
-    spanned::Spanned,
-    Attribute, Data, DeriveInput, LitStr, Path, Token,
+    parse::{Parse, ParseBuffer, ParseStream},
+    punctuated::Punctuated,
+    Attribute, Data, DeriveInput, LitInt, LitStr, Path, Token, Visibility,
 };
 
 pub fn parse_derive_input(input: DeriveInput) -> Result<Meta, syn::Error> {
-    let kinded_attrs: KindedAttributes = {
-        match find_kinded_attr(&input)? {
-            Some(kinded_attr) => syn::parse2(kinded_attr.to_token_stream())?,
+    let mut kinded_attrs: KindedAttributes = {
+        match find_kinded_attr(&input.attrs)? {
+            Some(kinded_attr) => syn::parse2(kinded_attr)?,
             None => KindedAttributes::default(),
         }
     };
 
-    let data = match input.data {
-        Data::Enum(enum_data) => enum_data,
-        Data::Struct(..) | Data::Union(..) => {
-            return Err(syn::Error::new(
-                input.ident.span(),
-                "Kinded can be derived only on enums",
-            ));
+    let (data_type, variants) = match input.data {
+        Data::Enum(enum_data) => {
+            let mut variants = enum_data
+                .variants
+                .iter()
+                .map(parse_variant)
+                .collect::<Result<Vec<_>, _>>()?;
+            resolve_variant_default(&mut kinded_attrs, &variants)?;
+            if kinded_attrs.display_serde {
+                kinded_attrs.display = find_serde_rename_all(&input.attrs)?;
+                for (variant, syn_variant) in variants.iter_mut().zip(&enum_data.variants) {
+                    if variant.kinded_attrs.rename.is_none() {
+                        variant.kinded_attrs.rename =
+                            find_serde_value(&syn_variant.attrs, "rename")?
+                                .map(|rename| rename.value());
+                    }
+                }
+            }
+            (DataType::Enum, variants)
+        }
+        Data::Struct(struct_data) => {
+            // A struct is treated like an enum with a single variant
+            let variant = Variant {
+                ident: input.ident.clone(),
+                fields_type: parse_fields_type(&struct_data.fields),
+                fields_count: struct_data.fields.len(),
+                fields: struct_data.fields.iter().cloned().collect(),
+                discriminant: None,
+                docs: Vec::new(),
+                cfgs: Vec::new(),
+                doc_hidden: false,
+                kinded_attrs: VariantAttributes::default(),
+            };
+            (DataType::Struct, vec![variant])
+        }
+        Data::Union(..) => {
+            let msg = "Kinded can not be derived on unions: a union doesn't store which of its fields is set, so its kind can't be known\n\
+                       Use an enum with a variant per field instead, which keeps track of the active variant";
+            return Err(syn::Error::new(input.ident.span(), msg));
         }
     };
 
-    Ok(Meta {
+    let non_exhaustive = input
+        .attrs
+        .iter()
+        .any(|attr| attr.path().is_ident("non_exhaustive"));
+
+    let repr = find_int_repr(&input.attrs)?;
+
+    let meta = Meta {
         vis: input.vis,
         ident: input.ident,
         generics: input.generics,
-        variants: data.variants.iter().map(parse_variant).collect(),
+        non_exhaustive,
+        repr,
+        data_type,
+        variants,
         kinded_attrs,
-    })
+    };
+    validate_kind_name(&meta)?;
+    validate_external(&meta)?;
+    validate_kind_variants(&meta)?;
+    validate_separator(&meta)?;
+    validate_display_names(&meta)?;
+    validate_serde(&meta)?;
+    validate_repr(&meta)?;
+    validate_default(&meta)?;
+    validate_no_derive(&meta)?;
+    validate_non_copy(&meta)?;
+    validate_stable_ids(&meta)?;
+    validate_fast_kind(&meta)?;
+    Ok(meta)
 }
 
-fn parse_variant(variant: &syn::Variant) -> Variant {
-    Variant {
+/// Take the default kind from `#[kinded(default)]` on a variant, as an alternative to `default = ...`.
+fn resolve_variant_default(
+    kinded_attrs: &mut KindedAttributes,
+    variants: &[Variant],
+) -> Result<(), syn::Error> {
+    let mut marked = variants
+        .iter()
+        .filter(|variant| variant.kinded_attrs.default);
+    let Some(variant) = marked.next() else {
+        return Ok(());
+    };
+    if let Some(other) = marked.next() {
+        let msg = format!(
+            "Only one variant can be marked with #[kinded(default)], but both `{}` and `{}` are",
+            variant.ident, other.ident
+        );
+        return Err(syn::Error::new(other.ident.span(), msg));
+    }
+    if let Some(ref default) = kinded_attrs.default {
+        let msg = format!(
+            "Default kind is specified twice: with `default = {default}` and with #[kinded(default)] on `{}`",
+            variant.ident
+        );
+        return Err(syn::Error::new(variant.ident.span(), msg));
+    }
+    kinded_attrs.default = Some(variant.ident.clone());
+    Ok(())
+}
+
+/// Ensure that the kind type name is a valid identifier and does not clash with the type it's derived from.
+fn validate_kind_name(meta: &Meta) -> Result<(), syn::Error> {
+    let is_ident = |name: &str| syn::parse_str::<Ident>(name).is_ok();
+    let name = meta.affixed_kind_name();
+    if meta.kinded_attrs.kind.is_none() && !is_ident(&name) {
+        // Blame the prefix, if it alone makes the name invalid, e.g. "1", otherwise the suffix
+        let (attr_name, affix, span) = match (&meta.kinded_attrs.prefix, &meta.kinded_attrs.suffix)
+        {
+            (Some((prefix, span)), _) if !is_ident(&format!("{prefix}{}", meta.ident)) => {
+                ("prefix", prefix, span)
+            }
+            (_, Some((suffix, span))) => ("suffix", suffix, span),
+            (Some((prefix, span)), None) => ("prefix", prefix, span),
+            (None, None) => unreachable!("the default name is a valid identifier"),
+        };
+        let msg = format!("Invalid value for {attr_name}: \"{affix}\"\n`{name}` is not a valid name for the kind type");
+        return Err(syn::Error::new(*span, msg));
+    }
+
+    let kind_name = meta.kind_name();
+    if kind_name == meta.ident {
+        let span = match meta.kinded_attrs.kind {
+            Some(ref kind) => kind.span(),
+            None => meta.ident.span(),
+        };
+        let msg = format!("The kind type cannot be named `{kind_name}`, because it's the name of the type Kinded is derived on.\nUse `kind = ...` attribute to give it a different name.");
+        return Err(syn::Error::new(span, msg));
+    }
+    Ok(())
+}
+
+/// Ensure that the kind type gets at least one variant, unless the enum has no variants at all.
+fn validate_kind_variants(meta: &Meta) -> Result<(), syn::Error> {
+    if !meta.variants.is_empty() && meta.kind_variants().next().is_none() {
+        let msg = "All the variants are skipped with #[kinded(skip)], so the kind type would have no variants.";
+        return Err(syn::Error::new(meta.ident.span(), msg));
+    }
+    Ok(())
+}
+
+/// The separator replaces the one of the display case of the enum, so the case must separate the words.
+fn validate_separator(meta: &Meta) -> Result<(), syn::Error> {
+    let Some((_, span)) = meta.kinded_attrs.separator else {
+        return Ok(());
+    };
+
+    let msg = if meta.kinded_attrs.display_serde {
+        "separator can not be used with display = \"serde\", because the names must match the ones of serde"
+    } else if meta
+        .kinded_attrs
+        .display
+        .is_some_and(DisplayCase::separates_words)
+    {
+        return Ok(());
+    } else {
+        "separator requires a display case which separates the words\nValid cases are: \"snake_case\", \"SCREAMING_SNAKE_CASE\", \"kebab-case\", \"SCREAMING-KEBAB-CASE\", \"Title Case\", \"Train-Case\""
+    };
+    Err(syn::Error::new(span, msg))
+}
+
+/// Ensure that all the variants are displayed differently, otherwise the generated
+/// implementation of FromStr would not be able to parse them back.
+fn validate_display_names(meta: &Meta) -> Result<(), syn::Error> {
+    if meta.kinded_attrs.no_from_str {
+        return Ok(());
+    }
+
+    let mut seen: Vec<(&Ident, String)> = Vec::with_capacity(meta.variants.len());
+    for variant in meta.kind_variants() {
+        let display_name = meta.display_name(variant);
+        if let Some((other_ident, _)) = seen.iter().find(|(_, name)| *name == display_name) {
+            let msg = format!(
+                "Variants `{other_ident}` and `{}` are both displayed as \"{display_name}\", so they cannot be parsed back.\nUse `no_from_str` attribute to skip the implementation of FromStr trait.",
+                variant.ident
+            );
+            return Err(syn::Error::new(variant.ident.span(), msg));
+        }
+        if meta.kinded_attrs.parse_case_insensitive {
+            if let Some((other_ident, other_name)) = seen
+                .iter()
+                .find(|(_, name)| to_lowercase(name) == to_lowercase(&display_name))
+            {
+                let msg = format!(
+                    "Variants `{other_ident}` and `{}` are displayed as \"{other_name}\" and \"{display_name}\", which differ only by case, so they cannot be parsed back with `parse_case_insensitive`.",
+                    variant.ident
+                );
+                return Err(syn::Error::new(variant.ident.span(), msg));
+            }
+        }
+        seen.push((&variant.ident, display_name));
+    }
+    Ok(())
+}
+
+/// Deserialize implementation relies on FromStr, so it cannot be skipped.
+fn validate_serde(meta: &Meta) -> Result<(), syn::Error> {
+    if meta.kinded_attrs.serde && meta.kinded_attrs.no_from_str {
+        let msg = "`serde` attribute cannot be combined with `no_from_str`, because deserialization relies on FromStr.";
+        return Err(syn::Error::new(meta.ident.span(), msg));
+    }
+    if meta.kinded_attrs.serde && meta.kinded_attrs.serde_repr {
+        let msg = "`serde` attribute cannot be combined with `serde_repr`, because the kind can be serialized either as its display name or as its index.";
+        return Err(syn::Error::new(meta.ident.span(), msg));
+    }
+    Ok(())
+}
+
+/// Ensure that the integer type given with `repr = ...` can hold all the kind variants.
+fn validate_repr(meta: &Meta) -> Result<(), syn::Error> {
+    let Some(ref repr) = meta.kinded_attrs.repr else {
+        return Ok(());
+    };
+
+    // The maximum number of variants a kind type can have with the given representation
+    let max_count: u128 = match repr.to_string().as_ref() {
+        "u8" => 1 << 8,
+        "i8" => 1 << 7,
+        "u16" => 1 << 16,
+        "i16" => 1 << 15,
+        "u32" | "u64" | "u128" | "usize" | "i32" | "i64" | "i128" | "isize" => u128::MAX,
+        _ => {
+            let msg = format!("Invalid value for repr: {repr}\nValid values are: u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize");
+            return Err(syn::Error::new(repr.span(), msg));
+        }
+    };
+
+    let count = meta.kind_variants().count();
+    if count as u128 > max_count {
+        let msg = format!("The kind type has {count} variants, but `{repr}` can represent only {max_count} of them");
+        return Err(syn::Error::new(repr.span(), msg));
+    }
+    Ok(())
+}
+
+/// Ensure that the variant given with `default = ...` exists in the kind type.
+fn validate_default(meta: &Meta) -> Result<(), syn::Error> {
+    let Some(ref default) = meta.kinded_attrs.default else {
+        return Ok(());
+    };
+
+    if !meta
+        .kind_variants()
+        .any(|variant| &variant.ident == default)
+    {
+        let kind_name = meta.kind_name();
+        let valid_values = meta
+            .kind_variants()
+            .map(|variant| variant.ident.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        let msg = format!("{kind_name} has no variant {default}\nValid values are: {valid_values}");
+        return Err(syn::Error::new(default.span(), msg));
+    }
+    Ok(())
+}
+
+fn validate_no_derive(meta: &Meta) -> Result<(), syn::Error> {
+    for removed_trait in meta.kinded_attrs.no_derive.iter().flatten() {
+        let Some(name) = trait_name(removed_trait) else {
+            continue;
+        };
+        if REQUIRED_DERIVE_TRAITS
+            .iter()
+            .any(|required| name == required)
+        {
+            let msg = format!("{name} can not be removed with no_derive, because the generated kind type relies on it");
+            return Err(syn::Error::new(name.span(), msg));
+        }
+        if !DEFAULT_DERIVE_TRAITS.iter().any(|default| name == default) {
+            let removable = DEFAULT_DERIVE_TRAITS
+                .iter()
+                .filter(|default| !REQUIRED_DERIVE_TRAITS.contains(default))
+                .copied()
+                .collect::<Vec<_>>()
+                .join(", ");
+            let msg = format!("{name} is not derived by default, so it can not be removed with no_derive\nValid values are: {removable}");
+            return Err(syn::Error::new(name.span(), msg));
+        }
+    }
+    Ok(())
+}
+
+/// Without `Copy` the items taking the kind by value are not generated, so the attributes
+/// relying on them would be silently ignored.
+fn validate_non_copy(meta: &Meta) -> Result<(), syn::Error> {
+    if meta.derives_copy() {
+        return Ok(());
+    }
+
+    let attrs = &meta.kinded_attrs;
+    let has_ids = meta
+        .variants
+        .iter()
+        .any(|variant| variant.kinded_attrs.id.is_some());
+    let used_attrs = [
+        ("serde_repr", attrs.serde_repr),
+        ("histogram", attrs.histogram),
+        ("set", attrs.set),
+        ("fast_kind", attrs.fast_kind.is_some()),
+        ("id", has_ids),
+    ];
+    let Some(attr_name) = first_used_attr(&used_attrs) else {
+        return Ok(());
+    };
+
+    let copy = meta
+        .kinded_attrs
+        .no_derive
+        .iter()
+        .flatten()
+        .filter_map(trait_name)
+        .find(|name| *name == "Copy")
+        .expect("Copy is removed with no_derive");
+    let msg = format!("{attr_name} can not be used with no_derive(Copy), because it needs to_index() and the other methods taking the kind by value");
+    Err(syn::Error::new(copy.span(), msg))
+}
+
+/// With `external` the kind type is written by the user, so only `kind()`, `is_kind()`, `into_kind()`
+/// and the Kinded trait are generated, and the attributes shaping the kind type would be silently ignored.
+fn validate_external(meta: &Meta) -> Result<(), syn::Error> {
+    let Some(span) = meta.kinded_attrs.external else {
+        return Ok(());
+    };
+
+    if meta.kinded_attrs.kind.is_none() {
+        let msg = "external requires the existing kind type to be given with `kind = ...`";
+        return Err(syn::Error::new(span, msg));
+    }
+
+    let attrs = &meta.kinded_attrs;
+    let mut used_attrs = code_customizing_attrs(meta);
+    used_attrs.extend([
+        ("prefix", attrs.prefix.is_some()),
+        ("suffix", attrs.suffix.is_some()),
+        ("derive", attrs.derive.is_some()),
+        ("no_derive", attrs.no_derive.is_some()),
+        ("attr", attrs.attrs.is_some()),
+        ("repr", attrs.repr.is_some()),
+        ("vis", attrs.vis.is_some()),
+        ("module", attrs.module.is_some()),
+        ("non_exhaustive", attrs.non_exhaustive),
+        ("must_use", attrs.must_use),
+    ]);
+    if let Some(attr_name) = first_used_attr(&used_attrs) {
+        let msg = format!(
+            "{attr_name} can not be used with external, because the kind type is not generated"
+        );
+        return Err(syn::Error::new(span, msg));
+    }
+    Ok(())
+}
+
+/// Attributes customizing the generated code beyond the kind type definition and `kind()`,
+/// paired with whether they are used.
+fn code_customizing_attrs(meta: &Meta) -> Vec<(&'static str, bool)> {
+    let attrs = &meta.kinded_attrs;
+    let has_variant_attr = |is_used: fn(&VariantAttributes) -> bool| {
+        meta.variants
+            .iter()
+            .any(|variant| is_used(&variant.kinded_attrs))
+    };
+    vec![
+        ("display", attrs.display.is_some() || attrs.display_serde),
+        ("separator", attrs.separator.is_some()),
+        ("display_original", attrs.display_original),
+        ("try_into_original", attrs.try_into_original),
+        ("eq_original", attrs.eq_original),
+        ("parse_case_insensitive", attrs.parse_case_insensitive),
+        ("no_from_str", attrs.no_from_str),
+        ("serde", attrs.serde),
+        ("serde_repr", attrs.serde_repr),
+        ("clap", attrs.clap),
+        ("strum", attrs.strum),
+        ("default", attrs.default.is_some()),
+        ("all_order", attrs.all_order.is_some()),
+        ("histogram", attrs.histogram),
+        ("set", attrs.set),
+        ("error", attrs.error),
+        ("fast_kind", attrs.fast_kind.is_some()),
+        ("id", has_variant_attr(|attrs| attrs.id.is_some())),
+        ("rename", has_variant_attr(|attrs| attrs.rename.is_some())),
+        ("display", has_variant_attr(|attrs| attrs.display.is_some())),
+    ]
+}
+
+fn first_used_attr(used_attrs: &[(&'static str, bool)]) -> Option<&'static str> {
+    used_attrs
+        .iter()
+        .find(|(_, is_used)| *is_used)
+        .map(|(attr_name, _)| *attr_name)
+}
+
+/// `fast_kind` reads the tag of the enum as the index of the kind, which is sound only
+/// when the tag has a known integer type and matches the declaration order of the kind variants.
+fn validate_fast_kind(meta: &Meta) -> Result<(), syn::Error> {
+    let Some(span) = meta.kinded_attrs.fast_kind else {
+        return Ok(());
+    };
+
+    let msg = if meta.data_type == DataType::Struct {
+        "fast_kind can be used only with enums"
+    } else if meta.repr.is_none() {
+        "fast_kind requires an integer representation of the enum, e.g. #[repr(u8)]"
+    } else if meta.has_discriminants() {
+        "fast_kind can not be used with explicit discriminants"
+    } else if meta
+        .variants
+        .iter()
+        .any(|variant| variant.kinded_attrs.skip)
+    {
+        "fast_kind can not be used with skipped variants"
+    } else {
+        return Ok(());
+    };
+    Err(syn::Error::new(span, msg))
+}
+
+/// Stable ids are either given for all the kind variants or for none of them,
+/// so a new variant can't silently get an id which is already persisted somewhere.
+fn validate_stable_ids(meta: &Meta) -> Result<(), syn::Error> {
+    if !meta.has_stable_ids() {
+        return Ok(());
+    }
+
+    let mut seen: Vec<(&Ident, u32)> = Vec::new();
+    for variant in meta.kind_variants() {
+        let Some((id, span)) = variant.kinded_attrs.id else {
+            let msg = format!(
+                "Variant `{}` has no stable id. Once `id = ...` is given to a variant, it must be given to all of them.",
+                variant.ident
+            );
+            return Err(syn::Error::new(variant.ident.span(), msg));
+        };
+        if let Some((other_ident, _)) = seen.iter().find(|(_, other_id)| *other_id == id) {
+            let msg = format!(
+                "Variants `{other_ident}` and `{}` have the same stable id {id}",
+                variant.ident
+            );
+            return Err(syn::Error::new(span, msg));
+        }
+        seen.push((&variant.ident, id));
+    }
+    Ok(())
+}
+
+fn parse_variant(variant: &syn::Variant) -> Result<Variant, syn::Error> {
+    let kinded_attrs: VariantAttributes = match find_kinded_attr(&variant.attrs)? {
+        Some(kinded_attr) => syn::parse2(kinded_attr)?,
+        None => VariantAttributes::default(),
+    };
+
+    Ok(Variant {
         ident: variant.ident.clone(),
         fields_type: parse_fields_type(&variant.fields),
-    }
+        fields_count: variant.fields.len(),
+        fields: variant.fields.iter().cloned().collect(),
+        discriminant: variant
+            .discriminant
+            .as_ref()
+            .map(|(_eq, expr)| expr.clone()),
+        docs: find_doc_attrs(&variant.attrs),
+        cfgs: find_cfg_attrs(&variant.attrs),
+        doc_hidden: is_doc_hidden(&variant.attrs),
+        kinded_attrs,
+    })
 }
 
 fn parse_fields_type(fields: &syn::Fields) -> FieldsType {
@@ -50,43 +497,173 @@ fn parse_fields_type(fields: &syn::Fields) -> FieldsType {
     }
 }
 
-/// Find `#[kinded(..)]` attribute on the enum.
-fn find_kinded_attr(input: &DeriveInput) -> Result<Option<&Attribute>, syn::Error> {
-    let kinded_attrs: Vec<_> = input
-        .attrs
+/// Find the integer type given with `#[repr(..)]`, e.g. `u16` in `#[repr(C, u16)]`.
+fn find_int_repr(attrs: &[Attribute]) -> Result<Option<Ident>, syn::Error> {
+    const INT_TYPES: &[&str] = &[
+        "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize",
+    ];
+
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("repr")) {
+        let mut int_repr = None;
+        attr.parse_nested_meta(|nested| {
+            if let Some(ident) = nested.path.get_ident() {
+                if INT_TYPES.iter().any(|int_type| ident == int_type) {
+                    int_repr = Some(ident.clone());
+                }
+            }
+            // Skip arguments like `align(8)`, they are irrelevant here
+            if nested.input.peek(syn::token::Paren) {
+                let _content;
+                parenthesized!(_content in nested.input);
+            }
+            Ok(())
+        })?;
+        if int_repr.is_some() {
+            return Ok(int_repr);
+        }
+    }
+    Ok(None)
+}
+
+/// Find the case given with `#[serde(rename_all = "...")]`, used with `display = "serde"`.
+fn find_serde_rename_all(attrs: &[Attribute]) -> Result<Option<DisplayCase>, syn::Error> {
+    find_serde_value(attrs, "rename_all")?
+        .map(|case_lit_str| display_case_from_lit(&case_lit_str))
+        .transpose()
+}
+
+/// Find the value of the given key in `#[serde(..)]` attributes, e.g. `rename` in `#[serde(rename = "mate")]`.
+/// For `key(serialize = "...", deserialize = "...")` form the value for serialization is taken.
+fn find_serde_value(attrs: &[Attribute], key: &str) -> Result<Option<LitStr>, syn::Error> {
+    let mut value = None;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("serde")) {
+        attr.parse_nested_meta(|nested| {
+            if nested.path.is_ident(key) {
+                if nested.input.peek(Token![=]) {
+                    value = Some(nested.value()?.parse()?);
+                } else {
+                    nested.parse_nested_meta(|inner| {
+                        let inner_value: LitStr = inner.value()?.parse()?;
+                        if inner.path.is_ident("serialize") {
+                            value = Some(inner_value);
+                        }
+                        Ok(())
+                    })?;
+                }
+            } else if nested.input.peek(Token![=]) {
+                // Skip other serde arguments, like `tag = "type"`
+                let _: syn::Expr = nested.value()?.parse()?;
+            } else if nested.input.peek(syn::token::Paren) {
+                let _content;
+                parenthesized!(_content in nested.input);
+            }
+            Ok(())
+        })?;
+    }
+    Ok(value)
+}
+
+/// Find `#[doc(..)]` attributes, including `///` comments.
+fn find_doc_attrs(attrs: &[Attribute]) -> Vec<Attribute> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("doc"))
+        .cloned()
+        .collect()
+}
+
+/// Check for `#[doc(hidden)]`, possibly combined with other arguments, e.g. `#[doc(hidden, alias = "..")]`.
+fn is_doc_hidden(attrs: &[Attribute]) -> bool {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("doc"))
+        .filter_map(|attr| {
+            attr.parse_args_with(Punctuated::<syn::Meta, Token![,]>::parse_terminated)
+                .ok()
+        })
+        .flatten()
+        .any(|meta| meta.path().is_ident("hidden"))
+}
+
+/// Find `#[cfg(..)]` attributes, so conditionally compiled variants stay conditional in the kind.
+fn find_cfg_attrs(attrs: &[Attribute]) -> Vec<Attribute> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("cfg"))
+        .cloned()
+        .collect()
+}
+
+/// Find all `#[kinded(..)]` attributes and merge them into a single one, so
+/// settings can be split across several attributes and duplicates are still detected.
+fn find_kinded_attr(attrs: &[Attribute]) -> Result<Option<TokenStream>, syn::Error> {
+    let kinded_attrs: Vec<_> = attrs
         .iter()
         .filter(|&attr| attr.path().is_ident("kinded"))
         .collect();
 
-    if kinded_attrs.len() > 1 {
-        let &attr = kinded_attrs.last().unwrap();
-        let span = attr.span();
-        let msg = "Multiple #[kinded(..)] attributes are not allowed.";
-        Err(syn::Error::new(span, msg))
-    } else {
-        let maybe_kinded_attr = kinded_attrs.into_iter().next();
-        Ok(maybe_kinded_attr)
+    if kinded_attrs.is_empty() {
+        return Ok(None);
     }
+
+    let mut merged_content = TokenStream::new();
+    for attr in kinded_attrs {
+        let content = attr.meta.require_list()?.tokens.clone();
+        let ends_with_comma = matches!(
+            content.clone().into_iter().last(),
+            Some(TokenTree::Punct(punct)) if punct.as_char() == ','
+        );
+        merged_content.extend(content);
+        if !ends_with_comma && !merged_content.is_empty() {
+            merged_content.extend(quote!(,));
+        }
+    }
+    Ok(Some(quote!(#[kinded(#merged_content)])))
 }
 
+/// Keys accepted by `#[kinded(..)]` on the enum, used to report unknown ones.
+const KINDED_ATTRIBUTE_NAMES: &[&str] = &[
+    "kind",
+    "prefix",
+    "suffix",
+    "variant_prefix",
+    "all_order",
+    "derive",
+    "no_derive",
+    "attr",
+    "display",
+    "separator",
+    "clap",
+    "strum",
+    "repr",
+    "vis",
+    "module",
+    "method",
+    "default",
+    "non_exhaustive",
+    "must_use",
+    "fast_kind",
+    "error",
+    "histogram",
+    "set",
+    "debug",
+    "display_original",
+    "eq_original",
+    "try_into_original",
+    "parse_case_insensitive",
+    "no_from_str",
+    "serde",
+    "serde_repr",
+    "external",
+];
+
+/// Keys accepted by `#[kinded(..)]` on a variant, used to report unknown ones.
+const VARIANT_ATTRIBUTE_NAMES: &[&str] = &["skip", "rename", "display", "default", "id"];
+
 impl Parse for KindedAttributes {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         let mut kinded_attrs = KindedAttributes::default();
-
-        // Unwrap the irrelevant part and reassign input to the relevant input:
-        //
-        //     #[kinded(  RELEVANT_INPUT  )]
-        //
-        let input = {
-            let _: Token!(#) = input.parse()?;
-            let bracketed_content;
-            bracketed!(bracketed_content in input);
-            let _kinded: Ident = bracketed_content.parse()?;
-
-            let parenthesized_content;
-            parenthesized!(parenthesized_content in bracketed_content);
-            parenthesized_content
-        };
+        let input = unwrap_kinded_attr(input)?;
 
         while !input.is_empty() {
             let attr_name: Ident = input.parse()?;
@@ -99,6 +676,86 @@ impl Parse for KindedAttributes {
                     let msg = format!("Duplicated attribute: {attr_name}");
                     return Err(syn::Error::new(attr_name.span(), msg));
                 }
+            } else if attr_name == "method" {
+                let _: Token!(=) = input.parse()?;
+                let method: Ident = input.parse()?;
+                if kinded_attrs.method.is_none() {
+                    kinded_attrs.method = Some(method);
+                } else {
+                    let msg = format!("Duplicated attribute: {attr_name}");
+                    return Err(syn::Error::new(attr_name.span(), msg));
+                }
+            } else if attr_name == "module" {
+                let _: Token!(=) = input.parse()?;
+                let module: Ident = input.parse()?;
+                if kinded_attrs.module.is_none() {
+                    kinded_attrs.module = Some(module);
+                } else {
+                    let msg = format!("Duplicated attribute: {attr_name}");
+                    return Err(syn::Error::new(attr_name.span(), msg));
+                }
+            } else if attr_name == "prefix" || attr_name == "suffix" {
+                let _: Token!(=) = input.parse()?;
+                let affix_lit_str: LitStr = input.parse()?;
+                let affix = affix_lit_str.value();
+                if !affix
+                    .chars()
+                    .all(|c| c.is_alphabetic() || c.is_ascii_digit() || c == '_')
+                {
+                    let msg = format!("Invalid value for {attr_name}: \"{affix}\"\nOnly letters, digits and underscores are allowed");
+                    return Err(syn::Error::new(affix_lit_str.span(), msg));
+                }
+                if attr_name == "prefix" && affix.starts_with(|c: char| c.is_ascii_digit()) {
+                    let msg = format!("Invalid value for {attr_name}: \"{affix}\"\nIt must not start with a digit");
+                    return Err(syn::Error::new(affix_lit_str.span(), msg));
+                }
+                let field = if attr_name == "prefix" {
+                    &mut kinded_attrs.prefix
+                } else {
+                    &mut kinded_attrs.suffix
+                };
+                if field.is_none() {
+                    *field = Some((affix, affix_lit_str.span()));
+                } else {
+                    let msg = format!("Duplicated attribute: {attr_name}");
+                    return Err(syn::Error::new(attr_name.span(), msg));
+                }
+            } else if attr_name == "variant_prefix" {
+                let _: Token!(=) = input.parse()?;
+                let prefix_lit_str: LitStr = input.parse()?;
+                let prefix = prefix_lit_str.value();
+                let is_valid = prefix
+                    .chars()
+                    .next()
+                    .is_some_and(|c| c.is_alphabetic() || c == '_')
+                    && prefix.chars().all(|c| c.is_alphanumeric() || c == '_');
+                if !is_valid {
+                    let msg = format!("Invalid value for {attr_name}: \"{prefix}\"\nIt must start with a letter or an underscore, followed by letters, digits and underscores");
+                    return Err(syn::Error::new(prefix_lit_str.span(), msg));
+                }
+                if kinded_attrs.variant_prefix.is_none() {
+                    kinded_attrs.variant_prefix = Some(prefix);
+                } else {
+                    let msg = format!("Duplicated attribute: {attr_name}");
+                    return Err(syn::Error::new(attr_name.span(), msg));
+                }
+            } else if attr_name == "all_order" {
+                let _: Token!(=) = input.parse()?;
+                let order_lit_str: LitStr = input.parse()?;
+                let order = match order_lit_str.value().as_ref() {
+                    "declaration" => AllOrder::Declaration,
+                    "name" => AllOrder::Name,
+                    other => {
+                        let msg = format!("Invalid value for {attr_name}: \"{other}\"\nValid values are: \"declaration\", \"name\"");
+                        return Err(syn::Error::new(order_lit_str.span(), msg));
+                    }
+                };
+                if kinded_attrs.all_order.is_none() {
+                    kinded_attrs.all_order = Some(order);
+                } else {
+                    let msg = format!("Duplicated attribute: {attr_name}");
+                    return Err(syn::Error::new(attr_name.span(), msg));
+                }
             } else if attr_name == "derive" {
                 let derive_input;
                 parenthesized!(derive_input in input);
@@ -110,47 +767,303 @@ impl Parse for KindedAttributes {
                     let msg = format!("Duplicated attribute: {attr_name}");
                     return Err(syn::Error::new(attr_name.span(), msg));
                 }
+            } else if attr_name == "no_derive" {
+                let no_derive_input;
+                parenthesized!(no_derive_input in input);
+                let parsed_traits = no_derive_input.parse_terminated(Path::parse, Token![,])?;
+                let traits: Vec<Path> = parsed_traits.into_iter().collect();
+                if kinded_attrs.no_derive.is_none() {
+                    kinded_attrs.no_derive = Some(traits);
+                } else {
+                    let msg = format!("Duplicated attribute: {attr_name}");
+                    return Err(syn::Error::new(attr_name.span(), msg));
+                }
+            } else if attr_name == "attr" {
+                let attr_input;
+                parenthesized!(attr_input in input);
+                let parsed_attrs = attr_input.parse_terminated(syn::Meta::parse, Token![,])?;
+                let attrs: Vec<syn::Meta> = parsed_attrs.into_iter().collect();
+                if kinded_attrs.attrs.is_none() {
+                    kinded_attrs.attrs = Some(attrs);
+                } else {
+                    let msg = format!("Duplicated attribute: {attr_name}");
+                    return Err(syn::Error::new(attr_name.span(), msg));
+                }
             } else if attr_name == "display" {
                 let _: Token!(=) = input.parse()?;
-                let case_lit_str: LitStr = input.parse()?;
-                let case = match case_lit_str.value().as_ref() {
-                    "snake_case" => DisplayCase::Snake,
-                    "camelCase" => DisplayCase::Camel,
-                    "PascalCase" => DisplayCase::Pascal,
-                    "SCREAMING_SNAKE_CASE" => DisplayCase::ScreamingSnake,
-                    "kebab-case" => DisplayCase::Kebab,
-                    "SCREAMING-KEBAB-CASE" => DisplayCase::ScreamingKebab,
-                    "Title Case" => DisplayCase::Title,
-                    "lowercase" => DisplayCase::Lower,
-                    "UPPERCASE" => DisplayCase::Upper,
-                    _ => {
-                        let valid_values = [
-                            "snake_case",
-                            "camelCase",
-                            "PascalCase",
-                            "SCREAMING_SNAKE_CASE",
-                            "kebab-case",
-                            "SCREAMING-KEBAB-CASE",
-                            "Title Case",
-                            "lowercase",
-                            "UPPERCASE",
-                        ]
-                        .map(|value| format!(r#""{value}""#))
-                        .join(", ");
-                        let given_value = format!(r#""{}""#, case_lit_str.value());
-                        let msg = format!("Invalid value for display: {given_value}\nValid values are: {valid_values}");
-                        return Err(syn::Error::new(case_lit_str.span(), msg));
-                    }
-                };
-                if kinded_attrs.derive.is_none() {
+                let is_duplicated = kinded_attrs.display.is_some() || kinded_attrs.display_serde;
+                if is_duplicated {
+                    let msg = format!("Duplicated attribute: {attr_name}");
+                    return Err(syn::Error::new(attr_name.span(), msg));
+                }
+                let fork = input.fork();
+                let is_serde = fork
+                    .parse::<LitStr>()
+                    .map(|lit_str| lit_str.value())
+                    .or_else(|_| fork.parse::<Ident>().map(|ident| ident.to_string()))
+                    .is_ok_and(|value| value == "serde");
+                if is_serde {
+                    let _: TokenTree = input.parse()?;
+                    kinded_attrs.display_serde = true;
+                } else {
+                    kinded_attrs.display = Some(parse_display_case(&input)?);
+                }
+            } else if attr_name == "separator" {
+                let _: Token!(=) = input.parse()?;
+                let separator_lit_str: LitStr = input.parse()?;
+                let separator = separator_lit_str.value();
+                if separator.is_empty() {
+                    let msg = format!("Invalid value for {attr_name}: it must not be empty\nUse display = \"lowercase\" to join the words without a separator");
+                    return Err(syn::Error::new(separator_lit_str.span(), msg));
+                }
+                if kinded_attrs.separator.is_none() {
+                    kinded_attrs.separator = Some((separator, separator_lit_str.span()));
+                } else {
+                    let msg = format!("Duplicated attribute: {attr_name}");
+                    return Err(syn::Error::new(attr_name.span(), msg));
+                }
+            } else if attr_name == "clap" {
+                if !cfg!(feature = "clap") {
+                    let msg =
+                        "`clap` attribute requires `clap` feature of kinded crate to be enabled";
+                    return Err(syn::Error::new(attr_name.span(), msg));
+                }
+                if !kinded_attrs.clap {
+                    kinded_attrs.clap = true;
+                } else {
+                    let msg = format!("Duplicated attribute: {attr_name}");
+                    return Err(syn::Error::new(attr_name.span(), msg));
+                }
+            } else if attr_name == "strum" {
+                if !cfg!(feature = "strum") {
+                    let msg =
+                        "`strum` attribute requires `strum` feature of kinded crate to be enabled";
+                    return Err(syn::Error::new(attr_name.span(), msg));
+                }
+                if !kinded_attrs.strum {
+                    kinded_attrs.strum = true;
+                } else {
+                    let msg = format!("Duplicated attribute: {attr_name}");
+                    return Err(syn::Error::new(attr_name.span(), msg));
+                }
+            } else if attr_name == "repr" {
+                let _: Token!(=) = input.parse()?;
+                let repr: Ident = input.parse()?;
+                if kinded_attrs.repr.is_none() {
+                    kinded_attrs.repr = Some(repr);
+                } else {
+                    let msg = format!("Duplicated attribute: {attr_name}");
+                    return Err(syn::Error::new(attr_name.span(), msg));
+                }
+            } else if attr_name == "vis" {
+                let _: Token!(=) = input.parse()?;
+                let vis: Visibility = input.parse()?;
+                if kinded_attrs.vis.is_none() {
+                    kinded_attrs.vis = Some(vis);
+                } else {
+                    let msg = format!("Duplicated attribute: {attr_name}");
+                    return Err(syn::Error::new(attr_name.span(), msg));
+                }
+            } else if attr_name == "default" {
+                let _: Token!(=) = input.parse()?;
+                let default: Ident = input.parse()?;
+                if kinded_attrs.default.is_none() {
+                    kinded_attrs.default = Some(default);
+                } else {
+                    let msg = format!("Duplicated attribute: {attr_name}");
+                    return Err(syn::Error::new(attr_name.span(), msg));
+                }
+            } else if attr_name == "non_exhaustive" {
+                if !kinded_attrs.non_exhaustive {
+                    kinded_attrs.non_exhaustive = true;
+                } else {
+                    let msg = format!("Duplicated attribute: {attr_name}");
+                    return Err(syn::Error::new(attr_name.span(), msg));
+                }
+            } else if attr_name == "histogram" {
+                if !kinded_attrs.histogram {
+                    kinded_attrs.histogram = true;
+                } else {
+                    let msg = format!("Duplicated attribute: {attr_name}");
+                    return Err(syn::Error::new(attr_name.span(), msg));
+                }
+            } else if attr_name == "set" {
+                if !kinded_attrs.set {
+                    kinded_attrs.set = true;
+                } else {
+                    let msg = format!("Duplicated attribute: {attr_name}");
+                    return Err(syn::Error::new(attr_name.span(), msg));
+                }
+            } else if attr_name == "error" {
+                if !cfg!(feature = "std") {
+                    let msg =
+                        "`error` attribute requires `std` feature of kinded crate to be enabled";
+                    return Err(syn::Error::new(attr_name.span(), msg));
+                }
+                if !kinded_attrs.error {
+                    kinded_attrs.error = true;
+                } else {
+                    let msg = format!("Duplicated attribute: {attr_name}");
+                    return Err(syn::Error::new(attr_name.span(), msg));
+                }
+            } else if attr_name == "fast_kind" {
+                if kinded_attrs.fast_kind.is_none() {
+                    kinded_attrs.fast_kind = Some(attr_name.span());
+                } else {
+                    let msg = format!("Duplicated attribute: {attr_name}");
+                    return Err(syn::Error::new(attr_name.span(), msg));
+                }
+            } else if attr_name == "external" {
+                if kinded_attrs.external.is_none() {
+                    kinded_attrs.external = Some(attr_name.span());
+                } else {
+                    let msg = format!("Duplicated attribute: {attr_name}");
+                    return Err(syn::Error::new(attr_name.span(), msg));
+                }
+            } else if attr_name == "must_use" {
+                if !kinded_attrs.must_use {
+                    kinded_attrs.must_use = true;
+                } else {
+                    let msg = format!("Duplicated attribute: {attr_name}");
+                    return Err(syn::Error::new(attr_name.span(), msg));
+                }
+            } else if attr_name == "debug" {
+                if !kinded_attrs.debug {
+                    kinded_attrs.debug = true;
+                } else {
+                    let msg = format!("Duplicated attribute: {attr_name}");
+                    return Err(syn::Error::new(attr_name.span(), msg));
+                }
+            } else if attr_name == "display_original" {
+                if !kinded_attrs.display_original {
+                    kinded_attrs.display_original = true;
+                } else {
+                    let msg = format!("Duplicated attribute: {attr_name}");
+                    return Err(syn::Error::new(attr_name.span(), msg));
+                }
+            } else if attr_name == "try_into_original" {
+                if !kinded_attrs.try_into_original {
+                    kinded_attrs.try_into_original = true;
+                } else {
+                    let msg = format!("Duplicated attribute: {attr_name}");
+                    return Err(syn::Error::new(attr_name.span(), msg));
+                }
+            } else if attr_name == "eq_original" {
+                if !kinded_attrs.eq_original {
+                    kinded_attrs.eq_original = true;
+                } else {
+                    let msg = format!("Duplicated attribute: {attr_name}");
+                    return Err(syn::Error::new(attr_name.span(), msg));
+                }
+            } else if attr_name == "parse_case_insensitive" {
+                if !kinded_attrs.parse_case_insensitive {
+                    kinded_attrs.parse_case_insensitive = true;
+                } else {
+                    let msg = format!("Duplicated attribute: {attr_name}");
+                    return Err(syn::Error::new(attr_name.span(), msg));
+                }
+            } else if attr_name == "no_from_str" {
+                if !kinded_attrs.no_from_str {
+                    kinded_attrs.no_from_str = true;
+                } else {
+                    let msg = format!("Duplicated attribute: {attr_name}");
+                    return Err(syn::Error::new(attr_name.span(), msg));
+                }
+            } else if attr_name == "serde" {
+                if !cfg!(feature = "serde") {
+                    let msg =
+                        "`serde` attribute requires `serde` feature of kinded crate to be enabled";
+                    return Err(syn::Error::new(attr_name.span(), msg));
+                }
+                if !kinded_attrs.serde {
+                    kinded_attrs.serde = true;
+                } else {
+                    let msg = format!("Duplicated attribute: {attr_name}");
+                    return Err(syn::Error::new(attr_name.span(), msg));
+                }
+            } else if attr_name == "serde_repr" {
+                if !cfg!(feature = "serde") {
+                    let msg =
+                        "`serde_repr` attribute requires `serde` feature of kinded crate to be enabled";
+                    return Err(syn::Error::new(attr_name.span(), msg));
+                }
+                if !kinded_attrs.serde_repr {
+                    kinded_attrs.serde_repr = true;
+                } else {
+                    let msg = format!("Duplicated attribute: {attr_name}");
+                    return Err(syn::Error::new(attr_name.span(), msg));
+                }
+            } else if attr_name == "derive_original" {
+                // A derive macro can only add items, it can't change the enum it's applied to
+                let msg = "derive_original is not supported: a derive macro can not change the derives of the enum itself\n\
+                           Add the derives to the enum directly, or use `display_original`, `eq_original` or `try_into_original` to get the traits implemented through the kind\n\
+                           See https://docs.rs/kinded/latest/kinded/#traits-of-the-main-enum";
+                return Err(syn::Error::new(attr_name.span(), msg));
+            } else {
+                return Err(unknown_attr_error(&attr_name, KINDED_ATTRIBUTE_NAMES));
+            }
+
+            // Parse `,` unless it's the end of the stream
+            if !input.is_empty() {
+                let _comma: Token![,] = input.parse()?;
+            }
+        }
+
+        Ok(kinded_attrs)
+    }
+}
+
+impl Parse for VariantAttributes {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut kinded_attrs = VariantAttributes::default();
+        let input = unwrap_kinded_attr(input)?;
+
+        while !input.is_empty() {
+            let attr_name: Ident = input.parse()?;
+            if attr_name == "skip" {
+                if !kinded_attrs.skip {
+                    kinded_attrs.skip = true;
+                } else {
+                    let msg = format!("Duplicated attribute: {attr_name}");
+                    return Err(syn::Error::new(attr_name.span(), msg));
+                }
+            } else if attr_name == "default" {
+                if !kinded_attrs.default {
+                    kinded_attrs.default = true;
+                } else {
+                    let msg = format!("Duplicated attribute: {attr_name}");
+                    return Err(syn::Error::new(attr_name.span(), msg));
+                }
+            } else if attr_name == "display" {
+                let _: Token!(=) = input.parse()?;
+                let case = parse_display_case(&input)?;
+                if kinded_attrs.display.is_none() {
                     kinded_attrs.display = Some(case);
                 } else {
                     let msg = format!("Duplicated attribute: {attr_name}");
                     return Err(syn::Error::new(attr_name.span(), msg));
                 }
+            } else if attr_name == "id" {
+                let _: Token!(=) = input.parse()?;
+                let id_lit: LitInt = input.parse()?;
+                let id: u32 = id_lit.base10_parse()?;
+                if kinded_attrs.id.is_none() {
+                    kinded_attrs.id = Some((id, id_lit.span()));
+                } else {
+                    let msg = format!("Duplicated attribute: {attr_name}");
+                    return Err(syn::Error::new(attr_name.span(), msg));
+                }
+            } else if attr_name == "rename" {
+                let _: Token!(=) = input.parse()?;
+                let rename: LitStr = input.parse()?;
+                if kinded_attrs.rename.is_none() {
+                    kinded_attrs.rename = Some(rename.value());
+                } else {
+                    let msg = format!("Duplicated attribute: {attr_name}");
+                    return Err(syn::Error::new(attr_name.span(), msg));
+                }
             } else {
-                let msg = format!("Unknown attribute: {attr_name}");
-                return Err(syn::Error::new(attr_name.span(), msg));
+                return Err(unknown_attr_error(&attr_name, VARIANT_ATTRIBUTE_NAMES));
             }
 
             // Parse `,` unless it's the end of the stream
@@ -162,3 +1075,128 @@ impl Parse for KindedAttributes {
         Ok(kinded_attrs)
     }
 }
+
+/// Parse a display case given either as a string literal, e.g. `"snake_case"`, or as an identifier,
+/// e.g. `snake_case`. Cases with `-` or spaces can't be identifiers, so they must be quoted.
+fn parse_display_case(input: ParseStream) -> syn::Result<DisplayCase> {
+    if input.peek(syn::Ident) {
+        let case_ident: Ident = input.parse()?;
+        let case_lit_str = LitStr::new(&case_ident.to_string(), case_ident.span());
+        return display_case_from_lit(&case_lit_str).map_err(|err| {
+            let msg = format!(
+                "{err}\nCases containing `-` or spaces must be quoted, e.g. \"kebab-case\""
+            );
+            syn::Error::new(case_ident.span(), msg)
+        });
+    }
+    let case_lit_str: LitStr = input.parse()?;
+    display_case_from_lit(&case_lit_str)
+}
+
+fn display_case_from_lit(case_lit_str: &LitStr) -> syn::Result<DisplayCase> {
+    match case_lit_str.value().as_ref() {
+        "snake_case" => Ok(DisplayCase::Snake),
+        "camelCase" => Ok(DisplayCase::Camel),
+        "PascalCase" => Ok(DisplayCase::Pascal),
+        "SCREAMING_SNAKE_CASE" => Ok(DisplayCase::ScreamingSnake),
+        "kebab-case" => Ok(DisplayCase::Kebab),
+        "SCREAMING-KEBAB-CASE" => Ok(DisplayCase::ScreamingKebab),
+        "Title Case" => Ok(DisplayCase::Title),
+        "Train-Case" => Ok(DisplayCase::Train),
+        // flatcase is the same as lowercase
+        "lowercase" | "flatcase" => Ok(DisplayCase::Lower),
+        "UPPERCASE" => Ok(DisplayCase::Upper),
+        _ => {
+            let valid_values = [
+                "snake_case",
+                "camelCase",
+                "PascalCase",
+                "SCREAMING_SNAKE_CASE",
+                "kebab-case",
+                "SCREAMING-KEBAB-CASE",
+                "Title Case",
+                "Train-Case",
+                "lowercase",
+                "flatcase",
+                "UPPERCASE",
+            ]
+            .map(|value| format!(r#""{value}""#))
+            .join(", ");
+            let given_value = format!(r#""{}""#, case_lit_str.value());
+            let msg = format!(
+                "Invalid value for display: {given_value}\nValid values are: {valid_values}"
+            );
+            Err(syn::Error::new(case_lit_str.span(), msg))
+        }
+    }
+}
+
+/// Build an error for an unknown key, suggesting the closest valid one, if there is any.
+fn unknown_attr_error(attr_name: &Ident, valid_names: &[&str]) -> syn::Error {
+    let name = attr_name.to_string();
+    let valid_values = valid_names
+        .iter()
+        .map(|value| format!("`{value}`"))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    // Consider only reasonably close names, otherwise the suggestion is more confusing than helpful
+    let suggestion = valid_names
+        .iter()
+        .map(|valid_name| (edit_distance(&name, valid_name), valid_name))
+        .filter(|(distance, valid_name)| *distance <= valid_name.len().max(3) / 3)
+        .min_by_key(|(distance, _)| *distance);
+
+    let msg = match suggestion {
+        Some((_, valid_name)) => format!(
+            "Unknown attribute: {name}\nDid you mean `{valid_name}`?\nValid attributes are: {valid_values}"
+        ),
+        None => format!("Unknown attribute: {name}\nValid attributes are: {valid_values}"),
+    };
+    syn::Error::new(attr_name.span(), msg)
+}
+
+/// Levenshtein distance between two strings, where a swap of two adjacent
+/// characters counts as a single edit (so typos like "dsiplay" are close to "display").
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    // distances[i][j] is the distance between the first i chars of a and the first j chars of b
+    let mut distances = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in distances.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, distance) in distances[0].iter_mut().enumerate() {
+        *distance = j;
+    }
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut distance = (distances[i - 1][j] + 1)
+                .min(distances[i][j - 1] + 1)
+                .min(distances[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                distance = distance.min(distances[i - 2][j - 2] + 1);
+            }
+            distances[i][j] = distance;
+        }
+    }
+    distances[a.len()][b.len()]
+}
+
+// Unwrap the irrelevant part and return the relevant input:
+//
+//     #[kinded(  RELEVANT_INPUT  )]
+//
+fn unwrap_kinded_attr<'a>(input: ParseStream<'a>) -> syn::Result<ParseBuffer<'a>> {
+    let _: Token!(#) = input.parse()?;
+    let bracketed_content;
+    bracketed!(bracketed_content in input);
+    let _kinded: Ident = bracketed_content.parse()?;
+
+    let parenthesized_content;
+    parenthesized!(parenthesized_content in bracketed_content);
+    Ok(parenthesized_content)
+}
@@ -0,0 +1,22 @@
+//! Ensures that kinded can be used in `no_std` environment with the default features disabled.
+//! CI builds this crate for a target that has no `std` at all.
+
+#![no_std]
+
+use kinded::Kinded;
+
+#[derive(Kinded)]
+#[kinded(display = "snake_case")]
+pub enum Drink {
+    Mate,
+    Coffee(u32),
+    Tea { caffeine: bool },
+}
+
+pub fn drink_name(drink: &Drink) -> &'static str {
+    drink.kind().as_str()
+}
+
+pub fn parse_drink_kind(name: &str) -> Option<DrinkKind> {
+    name.parse().ok()
+}
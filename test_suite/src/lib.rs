@@ -4,6 +4,10 @@
 
 extern crate alloc;
 
+// Derive macros of clap refer to `::std`
+#[cfg(all(test, feature = "clap"))]
+extern crate std;
+
 use kinded::Kinded;
 
 #[derive(Kinded)]
@@ -41,6 +45,72 @@ mod main_enum {
         }
     }
 
+    mod fn_is_kind {
+        use super::*;
+        use alloc::vec::Vec;
+
+        #[test]
+        fn should_filter_by_kind() {
+            let roles = [
+                Role::User(1),
+                Role::Guest,
+                Role::User(2),
+                Role::Admin { id: 3 },
+            ];
+            let users: Vec<&Role> = roles.iter().filter(|r| r.is_kind(RoleKind::User)).collect();
+            assert_eq!(users.len(), 2);
+            assert!(!Role::Guest.is_kind(RoleKind::Admin));
+        }
+    }
+
+    mod fn_into_kind {
+        use super::*;
+        use alloc::{vec, vec::Vec};
+
+        #[test]
+        fn should_consume_value_and_return_kind() {
+            let roles = vec![Role::Guest, Role::Admin { id: 1 }, Role::User(2)];
+            let kinds: Vec<RoleKind> = roles.into_iter().map(Role::into_kind).collect();
+            assert_eq!(kinds, [RoleKind::Guest, RoleKind::Admin, RoleKind::User]);
+        }
+    }
+
+    mod fn_kind_index {
+        use super::*;
+
+        #[test]
+        fn should_match_index_of_kind() {
+            let roles = [Role::Guest, Role::User(1), Role::Admin { id: 2 }];
+            for (index, role) in roles.iter().enumerate() {
+                assert_eq!(role.kind_index(), index);
+                assert_eq!(role.kind_index(), role.kind().to_index());
+            }
+        }
+
+        #[test]
+        fn should_be_usable_in_const_context() {
+            const INDEX: usize = Role::Admin { id: 1 }.kind_index();
+            assert_eq!(INDEX, 2);
+        }
+    }
+
+    mod const_fn_kind {
+        use super::*;
+
+        #[test]
+        fn should_be_usable_in_const_context() {
+            const KIND: RoleKind = Role::Admin { id: 1 }.kind();
+            assert_eq!(KIND, RoleKind::Admin);
+        }
+
+        #[test]
+        fn should_be_usable_to_build_lookup_tables() {
+            const ROLES: [Role; 2] = [Role::Guest, Role::User(1)];
+            const KINDS: [RoleKind; 2] = [ROLES[0].kind(), ROLES[1].kind()];
+            assert_eq!(KINDS, [RoleKind::Guest, RoleKind::User]);
+        }
+    }
+
     mod traits {
         use super::*;
         use kinded::Kinded;
@@ -54,11 +124,288 @@ mod main_enum {
             let admin = Role::Admin { id: 32 };
             assert_eq!(compute_kind(admin), RoleKind::Admin);
         }
+
+        #[test]
+        fn should_implement_kinded_for_references() {
+            let admin = Role::Admin { id: 32 };
+            assert_eq!(compute_kind(&admin), RoleKind::Admin);
+            assert_eq!(compute_kind(&&admin), RoleKind::Admin);
+        }
+
+        #[test]
+        fn should_provide_all_kinds() {
+            fn count_kinds<T: Kinded>() -> usize {
+                T::all_kinds().len()
+            }
+
+            #[derive(Kinded)]
+            struct Coffee;
+
+            assert_eq!(count_kinds::<Role>(), 3);
+            assert_eq!(count_kinds::<Coffee>(), 1);
+            assert_eq!(Role::all_kinds(), RoleKind::all());
+        }
+
+        #[test]
+        fn should_provide_kind_name() {
+            fn compute_kind_name<T: Kinded>(val: &T) -> &'static str
+            where
+                T::Kind: Into<&'static str>,
+            {
+                val.kind_name()
+            }
+
+            #[derive(Kinded)]
+            #[kinded(display = "snake_case")]
+            enum Drink {
+                HotMate,
+            }
+
+            assert_eq!(compute_kind_name(&Role::User(1)), "User");
+            assert_eq!(compute_kind_name(&Drink::HotMate), "hot_mate");
+        }
+
+        #[test]
+        fn should_allow_manual_impl_with_non_copy_kind() {
+            use alloc::borrow::Cow;
+
+            // Not `Copy`, because of `Cow`
+            #[derive(Debug, Clone, PartialEq, Eq)]
+            struct Category {
+                name: Cow<'static, str>,
+            }
+
+            impl kinded::Kind for Category {
+                fn all() -> &'static [Self] {
+                    &[
+                        Category {
+                            name: Cow::Borrowed("hot"),
+                        },
+                        Category {
+                            name: Cow::Borrowed("cold"),
+                        },
+                    ]
+                }
+            }
+
+            struct Drink {
+                temperature: i32,
+            }
+
+            impl Kinded for Drink {
+                type Kind = Category;
+
+                fn kind(&self) -> Category {
+                    let index = if self.temperature > 40 { 0 } else { 1 };
+                    <Category as kinded::Kind>::all()[index].clone()
+                }
+            }
+
+            let tea = Drink { temperature: 80 };
+            assert_eq!(compute_kind(&tea).name, "hot");
+            assert_eq!(Drink::all_kinds().len(), 2);
+        }
+    }
+    mod eq_original {
+        use super::*;
+        use alloc::string::String;
+
+        #[derive(Kinded, PartialEq)]
+        #[kinded(eq_original)]
+        enum Drink {
+            Mate,
+            Coffee(String),
+        }
+
+        #[test]
+        fn should_compare_enum_with_kind() {
+            assert!(Drink::Coffee("Latte".into()) == DrinkKind::Coffee);
+            assert!(Drink::Mate != DrinkKind::Coffee);
+        }
+
+        #[test]
+        fn should_compare_kind_with_enum() {
+            assert!(DrinkKind::Mate == Drink::Mate);
+            assert!(DrinkKind::Mate != Drink::Coffee("Espresso".into()));
+        }
+
+        #[test]
+        fn should_keep_derived_partial_eq() {
+            assert!(Drink::Coffee("Latte".into()) != Drink::Coffee("Espresso".into()));
+        }
+    }
+
+    mod from_kind {
+        use super::*;
+
+        #[test]
+        fn should_convert_kind_back_to_all_unit_enum() {
+            #[derive(Kinded, Debug, PartialEq)]
+            enum Drink {
+                Mate,
+                Coffee,
+                #[kinded(skip)]
+                Water,
+            }
+
+            for drink in [Drink::Mate, Drink::Coffee] {
+                let kind: DrinkKind = drink.kind();
+                assert_eq!(Drink::from(kind), drink);
+            }
+            let drink: Drink = DrinkKind::Coffee.into();
+            assert_eq!(drink, Drink::Coffee);
+        }
+
+        #[test]
+        fn should_convert_kind_back_to_unit_struct() {
+            #[derive(Kinded, Debug, PartialEq)]
+            struct Coffee;
+
+            assert_eq!(Coffee::from(CoffeeKind::Coffee), Coffee);
+        }
+    }
+
+    mod try_into_original {
+        use super::*;
+        use alloc::string::String;
+
+        struct NoDefault;
+
+        #[derive(Kinded)]
+        #[kinded(try_into_original)]
+        enum Drink {
+            Mate,
+            Coffee(String, u8),
+            Tea { variety: String, caffeine: bool },
+            Poison(NoDefault),
+            Mix { base: String, secret: NoDefault },
+        }
+
+        #[test]
+        fn should_construct_unit_variant() {
+            assert!(matches!(Drink::try_from(DrinkKind::Mate), Ok(Drink::Mate)));
+        }
+
+        #[test]
+        fn should_construct_variants_with_default_fields() {
+            match Drink::try_from(DrinkKind::Coffee) {
+                Ok(Drink::Coffee(name, size)) => {
+                    assert_eq!(name, "");
+                    assert_eq!(size, 0);
+                }
+                _ => panic!("Expected Drink::Coffee"),
+            }
+
+            match DrinkKind::Tea.try_into() {
+                Ok(Drink::Tea { variety, caffeine }) => {
+                    assert_eq!(variety, "");
+                    assert!(!caffeine);
+                }
+                _ => panic!("Expected Drink::Tea"),
+            }
+        }
+
+        #[test]
+        fn should_fail_for_variants_with_non_default_fields() {
+            assert!(matches!(
+                Drink::try_from(DrinkKind::Poison),
+                Err(DrinkKind::Poison)
+            ));
+            assert!(matches!(
+                Drink::try_from(DrinkKind::Mix),
+                Err(DrinkKind::Mix)
+            ));
+        }
+
+        #[test]
+        fn should_work_with_structs() {
+            #[derive(Kinded, Debug, PartialEq)]
+            #[kinded(try_into_original)]
+            struct Point {
+                x: i32,
+                y: i32,
+            }
+
+            assert_eq!(Point::try_from(PointKind::Point), Ok(Point { x: 0, y: 0 }));
+        }
+
+        #[test]
+        fn should_construct_variants_with_generic_fields() {
+            #[derive(Kinded, Debug, PartialEq)]
+            #[kinded(try_into_original)]
+            enum Slot<T> {
+                Empty,
+                Filled(T),
+                Pair { count: u8, item: T },
+            }
+
+            assert_eq!(Slot::<i32>::try_from(SlotKind::Empty), Ok(Slot::Empty));
+            assert_eq!(Slot::<i32>::try_from(SlotKind::Filled), Ok(Slot::Filled(0)));
+            assert_eq!(
+                Slot::<i32>::try_from(SlotKind::Pair),
+                Ok(Slot::Pair { count: 0, item: 0 })
+            );
+        }
+
+        #[test]
+        fn should_probe_fields_mentioning_generics_with_declared_bounds() {
+            #[derive(Kinded)]
+            #[kinded(try_into_original)]
+            enum Holder<T> {
+                Many(alloc::vec::Vec<T>),
+                Boxed(alloc::boxed::Box<T>),
+            }
+
+            // Vec<T> is Default for any T, Box<T> only if `T: Default` is declared on the enum
+            assert!(matches!(
+                Holder::<NoDefault>::try_from(HolderKind::Many),
+                Ok(Holder::Many(_))
+            ));
+            assert!(matches!(
+                Holder::<i32>::try_from(HolderKind::Boxed),
+                Err(HolderKind::Boxed)
+            ));
+        }
+
+        #[test]
+        fn should_not_conflict_with_from_for_unit_variants() {
+            #[derive(Kinded, Debug, PartialEq)]
+            #[kinded(try_into_original)]
+            enum Level {
+                Low,
+                High,
+            }
+
+            fn try_build<T: TryFrom<LevelKind>>(kind: LevelKind) -> Option<T> {
+                T::try_from(kind).ok()
+            }
+
+            assert_eq!(try_build::<Level>(LevelKind::High), Some(Level::High));
+        }
+    }
+
+    mod display_original {
+        use super::*;
+        use alloc::string::{String, ToString};
+
+        #[derive(Kinded)]
+        #[kinded(display = "snake_case", display_original)]
+        enum Drink {
+            Mate,
+            HotCoffee(String),
+        }
+
+        #[test]
+        fn should_display_the_kind() {
+            let coffee = Drink::HotCoffee("x".into());
+            assert_eq!(coffee.to_string(), coffee.kind().to_string());
+            assert_eq!(coffee.to_string(), "hot_coffee");
+        }
     }
 }
 
 mod kind_enum {
-    use super::RoleKind;
+    use super::{RoleKind, RoleKindIter};
 
     mod traits {
         extern crate alloc;
@@ -72,6 +419,7 @@ mod kind_enum {
         }
 
         #[test]
+        #[allow(clippy::clone_on_copy)]
         fn should_implement_clone() {
             let _ = RoleKind::Admin.clone();
         }
@@ -89,6 +437,137 @@ mod kind_enum {
             assert!(!RoleKind::Guest.eq(&RoleKind::User));
         }
 
+        #[test]
+        fn should_convert_into_static_str_and_string() {
+            use alloc::string::String;
+
+            #[derive(kinded::Kinded)]
+            #[kinded(display = "snake_case")]
+            enum Drink {
+                VeryHotBlackTea,
+                #[kinded(rename = "yerba")]
+                Mate,
+            }
+
+            fn into_string(value: impl Into<String>) -> String {
+                value.into()
+            }
+
+            let name: &'static str = DrinkKind::VeryHotBlackTea.into();
+            assert_eq!(name, "very_hot_black_tea");
+            assert_eq!(String::from(DrinkKind::Mate), "yerba");
+            assert_eq!(
+                into_string(DrinkKind::VeryHotBlackTea),
+                "very_hot_black_tea"
+            );
+        }
+
+        #[test]
+        fn should_implement_hash() {
+            extern crate std;
+            use std::collections::HashMap;
+
+            let mut counts: HashMap<RoleKind, usize> = HashMap::new();
+            for role in [Role::Guest, Role::User(1), Role::User(2)] {
+                *counts.entry(role.kind()).or_default() += 1;
+            }
+            assert_eq!(counts[&RoleKind::User], 2);
+            assert_eq!(counts[&RoleKind::Guest], 1);
+        }
+
+        mod hash_trait {
+            use core::hash::{Hash, Hasher};
+
+            use super::super::super::{Role, RoleKind};
+            use kinded::Kinded;
+
+            /// Records the written bytes, so the hashed data can be checked exactly.
+            #[derive(Default)]
+            struct RecordingHasher(alloc::vec::Vec<u8>);
+
+            impl Hasher for RecordingHasher {
+                fn finish(&self) -> u64 {
+                    0
+                }
+
+                fn write(&mut self, bytes: &[u8]) {
+                    self.0.extend_from_slice(bytes);
+                }
+            }
+
+            fn hashed_bytes(value: impl Hash) -> alloc::vec::Vec<u8> {
+                let mut hasher = RecordingHasher::default();
+                value.hash(&mut hasher);
+                hasher.0
+            }
+
+            #[test]
+            fn should_hash_equal_kinds_equally() {
+                let kind_a = Role::User(1).kind();
+                let kind_b = Role::User(2).kind();
+                assert_eq!(hashed_bytes(kind_a), hashed_bytes(kind_b));
+                assert_ne!(hashed_bytes(RoleKind::User), hashed_bytes(RoleKind::Guest));
+            }
+
+            #[test]
+            fn should_hash_declaration_index() {
+                for kind in RoleKind::all() {
+                    assert_eq!(hashed_bytes(kind), hashed_bytes(kind.to_index() as u64));
+                }
+            }
+
+            #[test]
+            fn should_ignore_discriminants() {
+                #[derive(kinded::Kinded)]
+                #[repr(u8)]
+                enum Status {
+                    Active = 10,
+                    Inactive = 20,
+                }
+
+                assert_eq!(hashed_bytes(StatusKind::Active), hashed_bytes(0u64));
+                assert_eq!(hashed_bytes(StatusKind::Inactive), hashed_bytes(1u64));
+            }
+
+            #[test]
+            fn should_hash_stable_id() {
+                #[derive(kinded::Kinded)]
+                enum Drink {
+                    #[kinded(id = 7)]
+                    Mate,
+                    #[kinded(id = 3)]
+                    Coffee,
+                }
+
+                assert_eq!(hashed_bytes(DrinkKind::Mate), hashed_bytes(7u64));
+                assert_eq!(hashed_bytes(DrinkKind::Coffee), hashed_bytes(3u64));
+            }
+        }
+
+        #[test]
+        fn should_implement_error() {
+            use alloc::{boxed::Box, string::ToString};
+            use core::error::Error;
+
+            #[derive(kinded::Kinded)]
+            #[kinded(error, display = "snake_case")]
+            enum FetchError {
+                Timeout,
+                NotFound(&'static str),
+            }
+
+            fn fetch(path: &'static str) -> Result<(), Box<dyn Error>> {
+                Err(FetchError::NotFound(path).kind().into())
+            }
+
+            let error = fetch("/mate").unwrap_err();
+            assert_eq!(error.to_string(), "not_found");
+            assert_eq!(
+                error.downcast_ref::<FetchErrorKind>(),
+                Some(&FetchErrorKind::NotFound)
+            );
+        }
+
         #[test]
         fn should_implement_from() {
             let user = Role::User(123);
@@ -116,6 +595,53 @@ mod kind_enum {
                 assert_eq!(format!("{user}"), "User");
             }
 
+            #[test]
+            fn should_respect_formatter_flags() {
+                #[derive(kinded::Kinded)]
+                enum Drink {
+                    Tea,
+                    Coffee,
+                }
+
+                assert_eq!(format!("{:>6}", DrinkKind::Tea), "   Tea");
+                assert_eq!(format!("{:<6}|", DrinkKind::Tea), "Tea   |");
+                assert_eq!(format!("{:^8}", DrinkKind::Tea), "  Tea   ");
+                assert_eq!(format!("{:-^10}", DrinkKind::Coffee), "--Coffee--");
+                assert_eq!(format!("{:.3}", DrinkKind::Coffee), "Cof");
+            }
+
+            #[test]
+            fn should_display_with_custom_separator() {
+                #[derive(kinded::Kinded)]
+                #[kinded(display = "snake_case", separator = ".")]
+                enum Drink {
+                    VeryHotBlackTea,
+                    Mate,
+                    #[kinded(display = "kebab-case")]
+                    IcedCoffee,
+                }
+
+                assert_eq!(DrinkKind::VeryHotBlackTea.to_string(), "very.hot.black.tea");
+                assert_eq!(DrinkKind::Mate.to_string(), "mate");
+                // The display case of a variant keeps its own separator
+                assert_eq!(DrinkKind::IcedCoffee.to_string(), "iced-coffee");
+                assert_eq!(
+                    "very.hot.black.tea".parse::<DrinkKind>().unwrap(),
+                    DrinkKind::VeryHotBlackTea
+                );
+            }
+
+            #[test]
+            fn should_display_title_case_with_custom_separator() {
+                #[derive(kinded::Kinded)]
+                #[kinded(display = "Title Case", separator = " / ")]
+                enum Drink {
+                    HotMate,
+                }
+
+                assert_eq!(DrinkKind::HotMate.to_string(), "Hot / Mate");
+            }
+
             #[test]
             fn should_display_snake_case() {
                 #[derive(kinded::Kinded)]
@@ -127,6 +653,20 @@ mod kind_enum {
                 assert_eq!(DrinkKind::HotMate.to_string(), "hot_mate")
             }
 
+            #[test]
+            fn should_accept_unquoted_display_case() {
+                #[derive(kinded::Kinded)]
+                #[kinded(display = snake_case)]
+                enum Drink {
+                    HotMate,
+                    #[kinded(display = SCREAMING_SNAKE_CASE)]
+                    IcedTea,
+                }
+
+                assert_eq!(DrinkKind::HotMate.to_string(), "hot_mate");
+                assert_eq!(DrinkKind::IcedTea.to_string(), "ICED_TEA");
+            }
+
             #[test]
             fn should_display_camel_case() {
                 #[derive(kinded::Kinded)]
@@ -193,6 +733,17 @@ mod kind_enum {
                 assert_eq!(DrinkKind::HotMate.to_string(), "Hot Mate")
             }
 
+            #[test]
+            fn should_display_train_case() {
+                #[derive(kinded::Kinded)]
+                #[kinded(display = "Train-Case")]
+                enum Drink {
+                    VeryHotBlackTea,
+                }
+
+                assert_eq!(DrinkKind::VeryHotBlackTea.to_string(), "Very-Hot-Black-Tea")
+            }
+
             #[test]
             fn should_display_lower_case() {
                 #[derive(kinded::Kinded)]
@@ -204,6 +755,17 @@ mod kind_enum {
                 assert_eq!(DrinkKind::HotMate.to_string(), "hotmate")
             }
 
+            #[test]
+            fn should_display_flat_case() {
+                #[derive(kinded::Kinded)]
+                #[kinded(display = "flatcase")]
+                enum Drink {
+                    VeryHotBlackTea,
+                }
+
+                assert_eq!(DrinkKind::VeryHotBlackTea.to_string(), "veryhotblacktea")
+            }
+
             #[test]
             fn should_display_upper_case() {
                 #[derive(kinded::Kinded)]
@@ -246,6 +808,7 @@ mod kind_enum {
                     "hot-mate", // kebab-case
                     "HOT-MATE", // SCREAMING-KEBAB-CASE
                     "Hot Mate", // Title Case
+                    "Hot-Mate", // Train-Case
                     "hotmate",  // lowercase
                     "HOTMATE",  // UPPERCASE
                 ];
@@ -267,29 +830,225 @@ mod kind_enum {
                 let error: kinded::ParseKindError = "Calabaza".parse::<MateKind>().unwrap_err();
                 assert_eq!(
                     error.to_string(),
-                    r#"Failed to parse "Calabaza" as MateKind"#
+                    r#"Failed to parse "Calabaza" as MateKind (expected one of: HotMate, Terere)"#
                 );
             }
 
+            #[test]
+            fn should_list_valid_names_in_error() {
+                #[derive(kinded::Kinded)]
+                #[kinded(display = "snake_case")]
+                enum Drink {
+                    Mate,
+                    Coffee,
+                    Tea,
+                }
+
+                let error = "foo".parse::<DrinkKind>().unwrap_err();
+                assert_eq!(error.valid_names(), DrinkKind::names());
+                let message = error.to_string();
+                for name in ["mate", "coffee", "tea"] {
+                    assert!(message.contains(name), "{message}");
+                }
+                assert!(message.ends_with("(expected one of: mate, coffee, tea)"));
+            }
+
             #[test]
             fn should_distinguish_very_similar_abbreviations() {
                 #[derive(kinded::Kinded)]
                 enum Db {
                     MySql,
-                    MySQL,
+                    Mysql,
                 }
 
                 assert_eq!("MySql".parse::<DbKind>().unwrap(), DbKind::MySql);
-                assert_eq!("MySQL".parse::<DbKind>().unwrap(), DbKind::MySQL);
+                assert_eq!("Mysql".parse::<DbKind>().unwrap(), DbKind::Mysql);
             }
-        }
-
-        mod kind_trait {
-            use crate::RoleKind;
 
             #[test]
-            fn should_implement_kind_trait() {
-                assert_eq!(
+            fn should_round_trip_with_display() {
+                #[derive(kinded::Kinded)]
+                #[kinded(display = "snake_case")]
+                enum Drink {
+                    VeryHotBlackTea,
+                    Milk,
+                }
+
+                for kind in DrinkKind::all() {
+                    let parsed: DrinkKind = kind.to_string().parse().unwrap();
+                    assert_eq!(&parsed, kind);
+                }
+                assert_eq!(
+                    "very_hot_black_tea".parse::<DrinkKind>().unwrap(),
+                    DrinkKind::VeryHotBlackTea
+                );
+            }
+
+            #[test]
+            fn should_parse_case_insensitive() {
+                #[derive(kinded::Kinded)]
+                #[kinded(display = "snake_case", parse_case_insensitive)]
+                enum Drink {
+                    VeryHotBlackTea,
+                    Milk,
+                }
+
+                for s in [
+                    "VERY_HOT_BLACK_TEA",
+                    "Very_Hot_Black_Tea",
+                    "very_hot_BLACK_tea",
+                ] {
+                    assert_eq!(s.parse::<DrinkKind>().unwrap(), DrinkKind::VeryHotBlackTea);
+                    assert_eq!(DrinkKind::try_from(s).unwrap(), DrinkKind::VeryHotBlackTea);
+                }
+                assert_eq!(DrinkKind::VeryHotBlackTea.as_str(), "very_hot_black_tea");
+                assert!("very-hot-black-tea!".parse::<DrinkKind>().is_err());
+            }
+
+            #[test]
+            fn should_handle_non_ascii_letters() {
+                #[derive(kinded::Kinded)]
+                #[kinded(display = "snake_case", parse_case_insensitive)]
+                enum Drink {
+                    ÜberMate,
+                    CaféCrème,
+                }
+
+                assert_eq!(DrinkKind::ÜberMate.as_str(), "über_mate");
+                assert_eq!(DrinkKind::CaféCrème.as_str(), "café_crème");
+                assert_eq!(
+                    "ÜBER_MATE".parse::<DrinkKind>().unwrap(),
+                    DrinkKind::ÜberMate
+                );
+                assert_eq!(
+                    "Café-Crème".parse::<DrinkKind>().unwrap(),
+                    DrinkKind::CaféCrème
+                );
+                // Only the case-insensitive parsing accepts these
+                assert_eq!(
+                    "CAFÉ_crème".parse::<DrinkKind>().unwrap(),
+                    DrinkKind::CaféCrème
+                );
+                assert_eq!(
+                    "Über_Mate".parse::<DrinkKind>().unwrap(),
+                    DrinkKind::ÜberMate
+                );
+            }
+
+            #[test]
+            fn should_implement_error_trait_for_parse_kind_error() {
+                fn receive_error<E: core::error::Error>(_: E) {}
+
+                receive_error("Calabaza".parse::<MateKind>().unwrap_err());
+            }
+
+            #[test]
+            fn should_allow_to_skip_from_str() {
+                #[derive(kinded::Kinded)]
+                #[kinded(no_from_str)]
+                enum Drink {
+                    Mate,
+                    Tea,
+                }
+
+                impl core::str::FromStr for DrinkKind {
+                    type Err = ();
+
+                    fn from_str(s: &str) -> Result<Self, Self::Err> {
+                        match s {
+                            "yerba" => Ok(DrinkKind::Mate),
+                            _ => Err(()),
+                        }
+                    }
+                }
+
+                assert_eq!("yerba".parse::<DrinkKind>(), Ok(DrinkKind::Mate));
+                assert_eq!("Mate".parse::<DrinkKind>(), Err(()));
+            }
+        }
+
+        mod from_traits {
+            extern crate alloc;
+            use alloc::string::String;
+
+            // Not Clone, so the conversions can't clone the data behind the scenes
+            struct Beans(String);
+
+            #[derive(kinded::Kinded)]
+            enum Drink {
+                Mate,
+                Coffee(String),
+                Espresso { beans: Beans },
+            }
+
+            #[test]
+            fn should_convert_owned_value() {
+                let coffee = Drink::Coffee(String::from("Arabica"));
+                assert_eq!(DrinkKind::from(coffee), DrinkKind::Coffee);
+
+                let espresso = Drink::Espresso {
+                    beans: Beans(String::from("Robusta")),
+                };
+                let kind: DrinkKind = espresso.into();
+                assert_eq!(kind, DrinkKind::Espresso);
+
+                assert_eq!(DrinkKind::from(Drink::Mate), DrinkKind::Mate);
+            }
+
+            #[test]
+            fn should_convert_reference() {
+                let coffee = Drink::Coffee(String::from("Arabica"));
+                assert_eq!(DrinkKind::from(&coffee), DrinkKind::Coffee);
+                // The value is only borrowed
+                let Drink::Coffee(variety) = coffee else {
+                    unreachable!()
+                };
+                assert_eq!(variety, "Arabica");
+            }
+        }
+
+        mod try_from_str_traits {
+            extern crate alloc;
+            use alloc::string::{String, ToString};
+
+            #[derive(kinded::Kinded)]
+            #[kinded(display = "snake_case")]
+            enum Drink {
+                VeryHotBlackTea,
+                Mate,
+            }
+
+            #[test]
+            fn should_implement_try_from_str() {
+                let kind = DrinkKind::try_from("very_hot_black_tea").unwrap();
+                assert_eq!(kind, DrinkKind::VeryHotBlackTea);
+
+                let error = DrinkKind::try_from("tereré").unwrap_err();
+                assert_eq!(
+                    error.to_string(),
+                    r#"Failed to parse "tereré" as DrinkKind (expected one of: very_hot_black_tea, mate)"#
+                );
+            }
+
+            #[test]
+            fn should_implement_try_from_string() {
+                let kind = DrinkKind::try_from(String::from("mate")).unwrap();
+                assert_eq!(kind, DrinkKind::Mate);
+
+                let error = DrinkKind::try_from(String::from("coffee")).unwrap_err();
+                assert_eq!(
+                    error.to_string(),
+                    r#"Failed to parse "coffee" as DrinkKind (expected one of: very_hot_black_tea, mate)"#
+                );
+            }
+        }
+
+        mod kind_trait {
+            use crate::RoleKind;
+
+            #[test]
+            fn should_implement_kind_trait() {
+                assert_eq!(
                     RoleKind::all(),
                     [RoleKind::Guest, RoleKind::User, RoleKind::Admin]
                 )
@@ -297,24 +1056,1954 @@ mod kind_enum {
         }
     }
 
+    mod const_count {
+        use super::RoleKind;
+
+        #[test]
+        fn should_be_equal_to_number_of_variants() {
+            assert_eq!(RoleKind::COUNT, 3);
+            assert_eq!(RoleKind::COUNT, RoleKind::all().len());
+        }
+
+        #[test]
+        fn should_be_usable_as_array_length() {
+            let mut counters = [0u32; RoleKind::COUNT];
+            counters[2] += 1;
+            assert_eq!(counters, [0, 0, 1]);
+        }
+
+        #[test]
+        fn should_be_zero_for_enum_without_variants() {
+            #[derive(kinded::Kinded)]
+            enum Nothing {}
+
+            assert_eq!(NothingKind::COUNT, 0);
+            assert!(NothingKind::all().is_empty());
+        }
+    }
+
+    mod const_all {
+        use super::RoleKind;
+
+        #[test]
+        fn should_contain_all_kinds_in_declaration_order() {
+            assert_eq!(
+                RoleKind::ALL,
+                [RoleKind::Guest, RoleKind::User, RoleKind::Admin]
+            );
+            assert_eq!(RoleKind::ALL.len(), RoleKind::COUNT);
+            assert_eq!(RoleKind::ALL, RoleKind::all());
+        }
+
+        #[test]
+        fn should_be_usable_in_const_context() {
+            const FIRST: RoleKind = RoleKind::ALL[0];
+            const KINDS: [RoleKind; RoleKind::COUNT] = RoleKind::ALL;
+            assert_eq!(FIRST, RoleKind::Guest);
+            assert_eq!(KINDS[2], RoleKind::Admin);
+        }
+    }
+
+    mod const_names {
+        use super::RoleKind;
+
+        #[test]
+        fn should_contain_names_of_all_kinds() {
+            extern crate alloc;
+            use alloc::vec::Vec;
+
+            let names: Vec<&str> = RoleKind::all().iter().map(|k| k.as_str()).collect();
+            assert_eq!(RoleKind::NAMES, ["Guest", "User", "Admin"]);
+            assert_eq!(RoleKind::names(), names);
+        }
+
+        #[test]
+        fn should_respect_display_case_and_skip() {
+            #[derive(kinded::Kinded)]
+            #[kinded(display = "kebab-case")]
+            enum Drink {
+                VeryHotBlackTea,
+                #[kinded(skip)]
+                Water,
+                #[kinded(rename = "yerba")]
+                Mate,
+            }
+
+            assert_eq!(DrinkKind::names(), ["very-hot-black-tea", "yerba"]);
+        }
+    }
+
+    mod fn_all_named {
+        use super::RoleKind;
+
+        #[test]
+        fn should_pair_kinds_with_names() {
+            let mut pairs = RoleKind::all_named();
+            for (kind, name) in RoleKind::all().iter().zip(RoleKind::names()) {
+                assert_eq!(pairs.next(), Some((*kind, *name)));
+            }
+            assert_eq!(pairs.next(), None);
+        }
+
+        #[test]
+        fn should_use_display_names() {
+            #[derive(kinded::Kinded)]
+            #[kinded(display = "snake_case")]
+            enum Drink {
+                HotMate,
+                #[kinded(rename = "java")]
+                Coffee,
+            }
+
+            let mut pairs = DrinkKind::all_named();
+            assert_eq!(pairs.next(), Some((DrinkKind::HotMate, "hot_mate")));
+            assert_eq!(pairs.next(), Some((DrinkKind::Coffee, "java")));
+        }
+    }
+
+    mod fn_for_each_kind {
+        extern crate alloc;
+        use alloc::vec::Vec;
+
+        use crate::RoleKind;
+
+        #[test]
+        fn should_call_closure_with_every_kind() {
+            let mut kinds = Vec::new();
+            RoleKind::for_each_kind(|kind| kinds.push(kind));
+            assert_eq!(kinds, RoleKind::all());
+        }
+
+        #[test]
+        fn should_fill_dispatch_table() {
+            let mut table = [""; RoleKind::COUNT];
+            RoleKind::for_each_kind(|kind| {
+                table[kind.to_index()] = match kind {
+                    RoleKind::Guest => "welcome",
+                    RoleKind::User => "dashboard",
+                    RoleKind::Admin => "settings",
+                };
+            });
+            assert_eq!(table, ["welcome", "dashboard", "settings"]);
+        }
+    }
+
+    mod fn_others {
+        extern crate alloc;
+        use alloc::vec::Vec;
+
+        use super::RoleKind;
+
+        #[test]
+        fn should_return_all_kinds_except_self() {
+            let others: Vec<_> = RoleKind::User.others().collect();
+            assert_eq!(others, [RoleKind::Guest, RoleKind::Admin]);
+        }
+
+        #[test]
+        fn should_return_nothing_for_single_variant() {
+            #[derive(kinded::Kinded)]
+            enum Single {
+                Alone,
+            }
+
+            assert_eq!(SingleKind::Alone.others().count(), 0);
+        }
+    }
+
+    mod fn_matching {
+        use super::RoleKind;
+
+        #[test]
+        fn should_return_kinds_satisfying_predicate() {
+            let mut kinds = RoleKind::matching(|kind| kind != RoleKind::User);
+            assert_eq!(kinds.next(), Some(RoleKind::Guest));
+            assert_eq!(kinds.next(), Some(RoleKind::Admin));
+            assert_eq!(kinds.next(), None);
+        }
+
+        #[test]
+        fn should_return_nothing_if_nothing_matches() {
+            assert_eq!(RoleKind::matching(|_| false).count(), 0);
+        }
+    }
+
+    mod fn_by_name {
+        use super::RoleKind;
+
+        #[test]
+        fn should_find_kind_by_display_name() {
+            #[derive(kinded::Kinded)]
+            #[kinded(display = "snake_case", no_from_str)]
+            enum Drink {
+                HotMate,
+                Coffee,
+            }
+
+            assert_eq!(DrinkKind::by_name("hot_mate"), Some(DrinkKind::HotMate));
+            assert_eq!(RoleKind::by_name("Admin"), Some(RoleKind::Admin));
+        }
+
+        #[test]
+        fn should_return_none_for_unknown_name() {
+            assert_eq!(RoleKind::by_name("Root"), None);
+            // Other spellings are accepted only by FromStr
+            assert_eq!(RoleKind::by_name("admin"), None);
+        }
+    }
+
+    mod fn_range {
+        extern crate alloc;
+        use alloc::vec::Vec;
+
+        use super::RoleKind;
+
+        fn collect(kinds: impl Iterator<Item = RoleKind>) -> Vec<RoleKind> {
+            kinds.collect()
+        }
+
+        #[test]
+        fn should_return_all_kinds_for_full_range() {
+            assert_eq!(collect(RoleKind::range(..)), RoleKind::all());
+        }
+
+        #[test]
+        fn should_respect_inclusive_and_exclusive_bounds() {
+            assert_eq!(
+                collect(RoleKind::range(RoleKind::User..=RoleKind::Admin)),
+                [RoleKind::User, RoleKind::Admin]
+            );
+            assert_eq!(
+                collect(RoleKind::range(RoleKind::Guest..RoleKind::Admin)),
+                [RoleKind::Guest, RoleKind::User]
+            );
+            assert_eq!(
+                collect(RoleKind::range(RoleKind::User..)),
+                [RoleKind::User, RoleKind::Admin]
+            );
+            assert_eq!(
+                collect(RoleKind::range(..RoleKind::User)),
+                [RoleKind::Guest]
+            );
+        }
+
+        #[test]
+        fn should_return_nothing_for_empty_range() {
+            assert_eq!(RoleKind::range(RoleKind::User..RoleKind::User).count(), 0);
+            assert_eq!(
+                RoleKind::range(RoleKind::Admin..=RoleKind::Guest).count(),
+                0
+            );
+        }
+    }
+
+    mod fn_index {
+        use super::RoleKind;
+
+        #[test]
+        fn should_return_declaration_index() {
+            assert_eq!(RoleKind::Guest.to_index(), 0);
+            assert_eq!(RoleKind::User.to_index(), 1);
+            assert_eq!(RoleKind::Admin.to_index(), 2);
+        }
+
+        #[test]
+        fn should_match_order_of_all() {
+            for (index, kind) in RoleKind::all().iter().enumerate() {
+                assert_eq!(kind.to_index(), index);
+            }
+        }
+
+        #[test]
+        fn should_round_trip_with_from_index() {
+            for &kind in RoleKind::all() {
+                assert_eq!(RoleKind::from_index(kind.to_index()), Some(kind));
+            }
+        }
+
+        #[test]
+        fn should_return_none_for_index_out_of_range() {
+            assert_eq!(RoleKind::from_index(3), None);
+            assert_eq!(RoleKind::from_index(usize::MAX), None);
+        }
+
+        #[test]
+        fn should_look_up_every_index_in_table() {
+            for index in 0..RoleKind::COUNT + 2 {
+                let expected = RoleKind::all().get(index).copied();
+                assert_eq!(RoleKind::INDEX_TO_KIND.get(index).copied(), expected);
+                assert_eq!(RoleKind::from_index(index), expected);
+            }
+        }
+
+        #[test]
+        fn should_be_const() {
+            const USER: Option<RoleKind> = RoleKind::from_index(1);
+            assert_eq!(USER, Some(RoleKind::User));
+        }
+    }
+
+    mod fn_next_prev {
+        use super::RoleKind;
+
+        #[test]
+        fn should_return_next_kind() {
+            assert_eq!(RoleKind::Guest.next(), RoleKind::User);
+            assert_eq!(RoleKind::User.next(), RoleKind::Admin);
+        }
+
+        #[test]
+        fn should_wrap_around_on_next() {
+            assert_eq!(RoleKind::Admin.next(), RoleKind::Guest);
+        }
+
+        #[test]
+        fn should_return_prev_kind() {
+            assert_eq!(RoleKind::Admin.prev(), RoleKind::User);
+            assert_eq!(RoleKind::User.prev(), RoleKind::Guest);
+        }
+
+        #[test]
+        fn should_wrap_around_on_prev() {
+            assert_eq!(RoleKind::Guest.prev(), RoleKind::Admin);
+        }
+
+        #[test]
+        fn should_not_wrap_around_on_checked_next_and_prev() {
+            assert_eq!(RoleKind::Guest.checked_next(), Some(RoleKind::User));
+            assert_eq!(RoleKind::Admin.checked_next(), None);
+            assert_eq!(RoleKind::Admin.checked_prev(), Some(RoleKind::User));
+            assert_eq!(RoleKind::Guest.checked_prev(), None);
+        }
+
+        #[test]
+        fn should_return_itself_for_single_variant() {
+            #[derive(kinded::Kinded)]
+            enum Single {
+                Alone,
+            }
+
+            assert_eq!(SingleKind::Alone.next(), SingleKind::Alone);
+            assert_eq!(SingleKind::Alone.prev(), SingleKind::Alone);
+            assert_eq!(SingleKind::Alone.checked_next(), None);
+            assert_eq!(SingleKind::Alone.checked_prev(), None);
+        }
+    }
+
+    mod fn_arity {
+        #[test]
+        fn should_return_number_of_fields() {
+            #[derive(kinded::Kinded)]
+            enum Drink {
+                Mate,
+                Coffee(&'static str, u8),
+                Tea {
+                    variety: &'static str,
+                    caffeine: bool,
+                    sugar: u8,
+                },
+            }
+
+            assert_eq!(DrinkKind::Mate.arity(), 0);
+            assert_eq!(DrinkKind::Coffee.arity(), 2);
+            assert_eq!(DrinkKind::Tea.arity(), 3);
+        }
+
+        #[test]
+        fn should_be_const() {
+            #[derive(kinded::Kinded)]
+            struct Point(i32, i32);
+
+            const ARITY: usize = PointKind::Point.arity();
+            assert_eq!(ARITY, 2);
+        }
+    }
+
+    mod const_index {
+        use crate::RoleKind;
+
+        // Guards against reordering of the variants at compile time
+        const _: () = assert!(RoleKind::ADMIN_INDEX == 2);
+
+        #[test]
+        fn should_match_to_index() {
+            assert_eq!(RoleKind::GUEST_INDEX, RoleKind::Guest.to_index());
+            assert_eq!(RoleKind::USER_INDEX, RoleKind::User.to_index());
+            assert_eq!(RoleKind::ADMIN_INDEX, RoleKind::Admin.to_index());
+        }
+
+        #[test]
+        fn should_use_screaming_snake_case_names() {
+            #[derive(kinded::Kinded)]
+            enum Drink {
+                HotMate,
+                #[kinded(skip)]
+                Water,
+                IcedHTTPCoffee,
+            }
+
+            assert_eq!(DrinkKind::HOT_MATE_INDEX, 0);
+            assert_eq!(DrinkKind::ICED_HTTP_COFFEE_INDEX, 1);
+            assert_eq!(
+                DrinkKind::from_index(DrinkKind::ICED_HTTP_COFFEE_INDEX),
+                Some(DrinkKind::IcedHTTPCoffee)
+            );
+        }
+    }
+
+    mod fn_is_variant {
+        use crate::RoleKind;
+
+        #[test]
+        fn should_return_true_only_for_matching_variant() {
+            assert!(RoleKind::Guest.is_guest());
+            assert!(!RoleKind::Guest.is_user());
+            assert!(!RoleKind::Guest.is_admin());
+
+            assert!(!RoleKind::User.is_guest());
+            assert!(RoleKind::User.is_user());
+            assert!(!RoleKind::User.is_admin());
+
+            assert!(!RoleKind::Admin.is_guest());
+            assert!(!RoleKind::Admin.is_user());
+            assert!(RoleKind::Admin.is_admin());
+        }
+
+        #[test]
+        fn should_use_snake_case_names() {
+            #[derive(kinded::Kinded)]
+            enum Drink {
+                HotMate,
+                IcedHTTPCoffee,
+                r#Type,
+            }
+
+            assert!(DrinkKind::HotMate.is_hot_mate());
+            assert!(DrinkKind::IcedHTTPCoffee.is_iced_http_coffee());
+            assert!(DrinkKind::Type.is_type());
+            assert!(!DrinkKind::Type.is_hot_mate());
+        }
+
+        #[test]
+        fn should_skip_colliding_names() {
+            #[derive(kinded::Kinded)]
+            enum Protocol {
+                HttpServer,
+                HTTPServer,
+                Ftp,
+            }
+
+            // Neither `is_http_server()` nor `HTTP_SERVER_INDEX` is generated, so they can be defined by hand
+            impl ProtocolKind {
+                fn is_http_server(self) -> bool {
+                    matches!(self, ProtocolKind::HttpServer | ProtocolKind::HTTPServer)
+                }
+
+                const HTTP_SERVER_INDEX: usize = 0;
+            }
+
+            assert!(ProtocolKind::HTTPServer.is_http_server());
+            assert!(!ProtocolKind::Ftp.is_http_server());
+            assert!(ProtocolKind::Ftp.is_ftp());
+            assert_eq!(ProtocolKind::FTP_INDEX, 2);
+            assert_eq!(ProtocolKind::HTTP_SERVER_INDEX, 0);
+        }
+
+        #[test]
+        fn should_be_const() {
+            const ADMINS: [bool; 3] = [
+                RoleKind::Guest.is_admin(),
+                RoleKind::User.is_admin(),
+                RoleKind::Admin.is_admin(),
+            ];
+            assert_eq!(ADMINS, [false, false, true]);
+        }
+    }
+
+    mod fn_fields_type {
+        use kinded::FieldsType;
+
+        #[derive(kinded::Kinded)]
+        enum Drink {
+            Mate,
+            Coffee(&'static str),
+            Tea { variety: &'static str },
+        }
+
+        #[test]
+        fn should_return_fields_type() {
+            assert_eq!(DrinkKind::Mate.fields_type(), FieldsType::Unit);
+            assert_eq!(DrinkKind::Coffee.fields_type(), FieldsType::Unnamed);
+            assert_eq!(DrinkKind::Tea.fields_type(), FieldsType::Named);
+        }
+    }
+
+    mod fn_as_str {
+        use super::RoleKind;
+
+        #[test]
+        fn should_return_variant_name() {
+            assert_eq!(RoleKind::Guest.as_str(), "Guest");
+            assert_eq!(RoleKind::Admin.as_str(), "Admin");
+        }
+
+        #[test]
+        fn should_respect_display_case() {
+            #[derive(kinded::Kinded)]
+            #[kinded(display = "snake_case")]
+            enum Drink {
+                VeryHotBlackTea,
+            }
+
+            assert_eq!(DrinkKind::VeryHotBlackTea.as_str(), "very_hot_black_tea");
+        }
+
+        #[test]
+        fn should_match_display() {
+            extern crate alloc;
+            use alloc::string::ToString;
+
+            for kind in RoleKind::all() {
+                assert_eq!(kind.as_str(), kind.to_string());
+            }
+        }
+
+        #[test]
+        fn should_be_const() {
+            const NAME: &str = RoleKind::User.as_str();
+            assert_eq!(NAME, "User");
+        }
+
+        #[test]
+        fn should_implement_as_ref_str() {
+            fn len(s: impl AsRef<str>) -> usize {
+                s.as_ref().len()
+            }
+
+            #[derive(kinded::Kinded)]
+            #[kinded(display = "snake_case")]
+            enum Drink {
+                VeryHotBlackTea,
+                #[kinded(rename = "yerba")]
+                Mate,
+            }
+
+            assert_eq!(DrinkKind::VeryHotBlackTea.as_ref(), "very_hot_black_tea");
+            assert_eq!(DrinkKind::Mate.as_ref(), "yerba");
+            assert_eq!(len(DrinkKind::Mate), 5);
+        }
+
+        #[test]
+        fn should_compare_with_str() {
+            #[derive(kinded::Kinded)]
+            #[kinded(display = "snake_case")]
+            enum Drink {
+                Mate,
+                Tea,
+            }
+
+            assert!(DrinkKind::Tea == *"tea");
+            assert!(DrinkKind::Mate != *"tea");
+            // The comparison is exact, unlike parsing
+            assert!(DrinkKind::Tea != *"Tea");
+        }
+    }
+
+    #[test]
+    fn should_iterate_all_kinds_in_reverse() {
+        extern crate alloc;
+        use alloc::vec::Vec;
+
+        let reversed: Vec<RoleKind> = RoleKind::all().iter().rev().copied().collect();
+        assert_eq!(reversed, [RoleKind::Admin, RoleKind::User, RoleKind::Guest]);
+    }
+
+    #[test]
+    fn should_provide_exact_size_iterator_over_all_kinds() {
+        fn exact_size(iter: impl DoubleEndedIterator + ExactSizeIterator) -> usize {
+            iter.len()
+        }
+
+        assert_eq!(exact_size(RoleKind::all().iter()), RoleKind::COUNT);
+        assert_eq!(RoleKind::all().len(), RoleKind::COUNT);
+    }
+
+    #[test]
+    fn should_iterate_kinds_by_value_in_reverse() {
+        extern crate alloc;
+        use alloc::vec::Vec;
+
+        let reversed: Vec<RoleKind> = RoleKind::iter().rev().collect();
+        assert_eq!(reversed, [RoleKind::Admin, RoleKind::User, RoleKind::Guest]);
+    }
+
+    #[test]
+    fn should_know_length_of_kinds_iterator() {
+        let mut kinds = RoleKind::iter();
+        assert_eq!(kinds.len(), RoleKind::COUNT);
+
+        kinds.next_back();
+        assert_eq!(kinds.len(), RoleKind::COUNT - 1);
+        assert_eq!(kinds.next(), Some(RoleKind::Guest));
+        assert_eq!(kinds.next(), Some(RoleKind::User));
+        assert_eq!(kinds.next(), None);
+    }
+
+    #[test]
+    fn should_allow_storing_named_iterator_type() {
+        struct Menu {
+            kinds: RoleKindIter,
+        }
+
+        let mut menu = Menu {
+            kinds: RoleKind::iter(),
+        };
+        assert_eq!(menu.kinds.next(), Some(RoleKind::Guest));
+
+        // The iterator is Clone, so the remaining kinds can be looked at without consuming them
+        let rest: [RoleKind; 2] = core::array::from_fn({
+            let mut kinds = menu.kinds.clone();
+            move |_| kinds.next().unwrap()
+        });
+        assert_eq!(rest, [RoleKind::User, RoleKind::Admin]);
+        assert_eq!(menu.kinds.len(), 2);
+    }
+
+    #[test]
+    fn should_provide_all_function_that_returns_iterator() {
+        fn impl_iter(_: impl IntoIterator<Item = &'static RoleKind>) {}
+        impl_iter(RoleKind::all());
+    }
+
+    #[test]
+    fn should_allow_storing_iterator_over_all_kinds() {
+        // `all()` returns a slice, so its iterator has a nameable type
+        struct Cycle {
+            kinds: core::slice::Iter<'static, RoleKind>,
+        }
+
+        impl Iterator for Cycle {
+            type Item = RoleKind;
+
+            fn next(&mut self) -> Option<RoleKind> {
+                match self.kinds.next() {
+                    Some(kind) => Some(*kind),
+                    None => {
+                        self.kinds = RoleKind::all().iter();
+                        self.kinds.next().copied()
+                    }
+                }
+            }
+        }
+
+        let mut cycle = Cycle {
+            kinds: RoleKind::all().iter(),
+        };
+        let kinds: [RoleKind; 4] = core::array::from_fn(|_| cycle.next().unwrap());
+        assert_eq!(
+            kinds,
+            [
+                RoleKind::Guest,
+                RoleKind::User,
+                RoleKind::Admin,
+                RoleKind::Guest
+            ]
+        );
+
+        let owned: core::array::IntoIter<RoleKind, { RoleKind::COUNT }> = RoleKind::ALL.into_iter();
+        assert_eq!(owned.len(), RoleKind::COUNT);
+    }
+}
+
+mod collect {
+    extern crate alloc;
+    use alloc::{borrow::ToOwned, vec};
+    use kinded::Kinded;
+
+    #[derive(Kinded, Debug, PartialEq)]
+    enum Drink {
+        Mate,
+        Coffee(alloc::string::String),
+        Tea { caffeine: bool },
+    }
+
+    #[test]
+    fn should_count_by_kind() {
+        let drinks = vec![
+            Drink::Mate,
+            Drink::Coffee("Espresso".to_owned()),
+            Drink::Tea { caffeine: true },
+            Drink::Mate,
+            Drink::Mate,
+        ];
+        let counts = kinded::count_by_kind(drinks);
+        assert_eq!(counts.len(), 3);
+        assert_eq!(counts[&DrinkKind::Mate], 3);
+        assert_eq!(counts[&DrinkKind::Coffee], 1);
+        assert_eq!(counts[&DrinkKind::Tea], 1);
+    }
+
+    #[test]
+    fn should_count_references_by_kind() {
+        let drinks = [Drink::Mate, Drink::Tea { caffeine: true }, Drink::Mate];
+        let counts = kinded::count_by_kind(drinks.iter());
+        assert_eq!(counts[&DrinkKind::Mate], 2);
+    }
+
+    #[test]
+    fn should_skip_absent_kinds() {
+        let counts = kinded::count_by_kind([Drink::Mate]);
+        assert_eq!(counts.get(&DrinkKind::Coffee), None);
+    }
+
+    #[test]
+    fn should_group_by_kind_keeping_order() {
+        let drinks = vec![
+            Drink::Coffee("Espresso".to_owned()),
+            Drink::Mate,
+            Drink::Tea { caffeine: false },
+            Drink::Coffee("Latte".to_owned()),
+        ];
+        let groups = kinded::group_by_kind(drinks);
+        assert_eq!(groups.len(), 3);
+        assert_eq!(
+            groups[&DrinkKind::Coffee],
+            [
+                Drink::Coffee("Espresso".to_owned()),
+                Drink::Coffee("Latte".to_owned())
+            ]
+        );
+        assert_eq!(groups[&DrinkKind::Mate], [Drink::Mate]);
+        assert_eq!(groups[&DrinkKind::Tea], [Drink::Tea { caffeine: false }]);
+    }
+}
+
+mod kind_module {
+    mod drinks {
+        #[derive(kinded::Kinded)]
+        #[kinded(module = kinds, display = "snake_case")]
+        pub enum Drink {
+            Mate,
+            Coffee(&'static str),
+            Tea { caffeine: bool },
+        }
+
+        #[derive(kinded::Kinded)]
+        #[kinded(module = private_kinds)]
+        enum Secret {
+            Recipe(&'static str),
+        }
+
+        #[test]
+        fn should_reach_private_kind_from_enclosing_module() {
+            let recipe = Secret::Recipe("Cortado");
+            assert_eq!(recipe.kind(), private_kinds::SecretKind::Recipe);
+        }
+    }
+
+    use drinks::{kinds::DrinkKind, Drink};
+    use kinded::Kinded;
+
+    #[test]
+    fn should_generate_kind_in_module() {
+        assert_eq!(Drink::Coffee("Espresso").kind(), DrinkKind::Coffee);
+        assert_eq!(DrinkKind::Tea.as_str(), "tea");
+    }
+
+    #[test]
+    fn should_refer_to_kind_in_module_from_kinded_trait() {
+        fn kind_of<T: Kinded>(value: &T) -> T::Kind {
+            value.kind()
+        }
+
+        assert_eq!(kind_of(&Drink::Mate), drinks::kinds::DrinkKind::Mate);
+    }
+
+    #[test]
+    fn should_generate_iterator_type_in_module() {
+        let kinds: drinks::kinds::DrinkKindIter = DrinkKind::iter();
+        assert_eq!(kinds.len(), DrinkKind::COUNT);
+    }
+}
+
+mod skip_variant {
+    extern crate alloc;
+    use alloc::string::ToString;
+
+    #[derive(kinded::Kinded)]
+    enum Drink {
+        Mate,
+        #[kinded(skip)]
+        Poison(u32),
+        Tea {
+            caffeine: bool,
+        },
+    }
+
+    #[test]
+    fn should_exclude_skipped_variant_from_kind() {
+        assert_eq!(DrinkKind::all(), [DrinkKind::Mate, DrinkKind::Tea]);
+        assert_eq!(DrinkKind::COUNT, 2);
+    }
+
+    #[test]
+    fn should_compute_kind_of_other_variants() {
+        assert_eq!(Drink::Mate.kind(), DrinkKind::Mate);
+        assert_eq!(Drink::Tea { caffeine: true }.kind(), DrinkKind::Tea);
+        assert_eq!(DrinkKind::from(&Drink::Mate), DrinkKind::Mate);
+    }
+
+    #[test]
+    #[should_panic(expected = "Drink::Poison is skipped with #[kinded(skip)] and has no kind")]
+    fn should_panic_on_kind_of_skipped_variant() {
+        Drink::Poison(1).kind();
+    }
+
+    #[test]
+    fn should_not_display_or_parse_skipped_variant() {
+        assert_eq!(DrinkKind::Tea.to_string(), "Tea");
+        assert!("Poison".parse::<DrinkKind>().is_err());
+    }
+}
+
+mod rename_variant {
+    extern crate alloc;
+    use alloc::string::ToString;
+
+    #[derive(kinded::Kinded)]
+    #[kinded(display = "snake_case")]
+    enum Protocol {
+        #[kinded(rename = "h2")]
+        HTTP2,
+        WebSocket,
+    }
+
+    #[test]
+    fn should_display_renamed_variant() {
+        assert_eq!(ProtocolKind::HTTP2.to_string(), "h2");
+        assert_eq!(ProtocolKind::HTTP2.as_str(), "h2");
+    }
+
+    #[test]
+    fn should_apply_display_case_to_other_variants() {
+        assert_eq!(ProtocolKind::WebSocket.to_string(), "web_socket");
+    }
+
+    #[test]
+    fn should_parse_renamed_variant() {
+        assert_eq!("h2".parse::<ProtocolKind>().unwrap(), ProtocolKind::HTTP2);
+        assert_eq!(
+            "web_socket".parse::<ProtocolKind>().unwrap(),
+            ProtocolKind::WebSocket
+        );
+    }
+}
+
+mod cfg_variant {
+    #[derive(kinded::Kinded)]
+    #[kinded(display = "snake_case")]
+    enum Drink {
+        Mate,
+        #[cfg(feature = "experimental")]
+        HotCoffee(&'static str),
+        Tea {
+            caffeine: bool,
+        },
+    }
+
+    #[test]
+    fn should_match_kind_of_enabled_variants() {
+        assert_eq!(Drink::Mate.kind(), DrinkKind::Mate);
+        assert_eq!(Drink::Tea { caffeine: true }.kind(), DrinkKind::Tea);
+    }
+
+    #[cfg(feature = "experimental")]
+    #[test]
+    fn should_include_variant_when_cfg_is_enabled() {
+        assert_eq!(Drink::HotCoffee("Espresso").kind(), DrinkKind::HotCoffee);
+        assert_eq!(DrinkKind::COUNT, 3);
+        assert_eq!(
+            DrinkKind::all(),
+            [DrinkKind::Mate, DrinkKind::HotCoffee, DrinkKind::Tea]
+        );
+        assert_eq!(DrinkKind::names(), ["mate", "hot_coffee", "tea"]);
+        assert_eq!(DrinkKind::Tea.to_index(), 2);
+        assert_eq!(
+            "hot_coffee".parse::<DrinkKind>().unwrap(),
+            DrinkKind::HotCoffee
+        );
+    }
+
+    #[cfg(not(feature = "experimental"))]
+    #[test]
+    fn should_exclude_variant_when_cfg_is_disabled() {
+        assert_eq!(DrinkKind::COUNT, 2);
+        assert_eq!(DrinkKind::all(), [DrinkKind::Mate, DrinkKind::Tea]);
+        assert_eq!(DrinkKind::names(), ["mate", "tea"]);
+        assert_eq!(DrinkKind::Tea.to_index(), 1);
+        assert_eq!(DrinkKind::from_index(1), Some(DrinkKind::Tea));
+        assert!("hot_coffee".parse::<DrinkKind>().is_err());
+    }
+}
+
+#[cfg(test)]
+mod display_serde {
+    extern crate alloc;
+    use alloc::string::ToString;
+
+    #[derive(kinded::Kinded, serde::Serialize)]
+    #[serde(tag = "type", rename_all = "kebab-case")]
+    #[kinded(display = "serde")]
+    enum Drink {
+        HotMate,
+        #[serde(rename = "espresso")]
+        StrongCoffee {
+            shots: u8,
+        },
+        #[serde(rename(serialize = "green", deserialize = "green-tea"))]
+        GreenTea,
+    }
+
+    #[derive(kinded::Kinded)]
+    #[kinded(display = "serde")]
+    enum Plain {
+        HotMate,
+    }
+
+    #[test]
+    fn should_apply_serde_rename_all() {
+        assert_eq!(DrinkKind::HotMate.to_string(), "hot-mate");
+    }
+
+    #[test]
+    fn should_apply_serde_rename_of_variant() {
+        assert_eq!(DrinkKind::StrongCoffee.as_str(), "espresso");
+        assert_eq!(DrinkKind::GreenTea.as_str(), "green");
+        assert_eq!(
+            "espresso".parse::<DrinkKind>().unwrap(),
+            DrinkKind::StrongCoffee
+        );
+    }
+
+    #[test]
+    fn should_match_serialized_tag() {
+        let json = serde_json::to_value(Drink::StrongCoffee { shots: 2 }).unwrap();
+        assert_eq!(json["type"], DrinkKind::StrongCoffee.as_str());
+    }
+
+    #[test]
+    fn should_fall_back_to_variant_name_without_serde_attributes() {
+        assert_eq!(PlainKind::HotMate.to_string(), "HotMate");
+    }
+
+    #[test]
+    fn should_split_words_like_serde() {
+        macro_rules! assert_same_as_serde {
+            ($case:literal) => {{
+                #[derive(kinded::Kinded, serde::Serialize)]
+                #[serde(rename_all = $case)]
+                #[kinded(display = "serde")]
+                enum Protocol {
+                    Http2,
+                    HTTPServer,
+                    Ipv4Addr,
+                    Tls13Handshake,
+                }
+
+                for (value, kind) in [
+                    (Protocol::Http2, ProtocolKind::Http2),
+                    (Protocol::HTTPServer, ProtocolKind::HTTPServer),
+                    (Protocol::Ipv4Addr, ProtocolKind::Ipv4Addr),
+                    (Protocol::Tls13Handshake, ProtocolKind::Tls13Handshake),
+                ] {
+                    let json = serde_json::to_string(&value).unwrap();
+                    assert_eq!(
+                        json.trim_matches('"'),
+                        kind.as_str(),
+                        "rename_all = {}",
+                        $case
+                    );
+                }
+            }};
+        }
+
+        assert_same_as_serde!("lowercase");
+        assert_same_as_serde!("UPPERCASE");
+        assert_same_as_serde!("PascalCase");
+        assert_same_as_serde!("camelCase");
+        assert_same_as_serde!("snake_case");
+        assert_same_as_serde!("SCREAMING_SNAKE_CASE");
+        assert_same_as_serde!("kebab-case");
+        assert_same_as_serde!("SCREAMING-KEBAB-CASE");
+    }
+
+    #[test]
+    fn should_give_serde_names_for_acronyms_and_digits() {
+        #[derive(kinded::Kinded, serde::Serialize)]
+        #[serde(rename_all = "snake_case")]
+        #[kinded(display = "serde")]
+        enum Protocol {
+            Http2,
+            HTTPServer,
+            Ipv4Addr,
+        }
+
+        assert_eq!(ProtocolKind::Http2.as_str(), "http2");
+        assert_eq!(ProtocolKind::HTTPServer.as_str(), "h_t_t_p_server");
+        assert_eq!(ProtocolKind::Ipv4Addr.as_str(), "ipv4_addr");
+    }
+
+    #[test]
+    fn should_accept_unquoted_serde() {
+        #[derive(kinded::Kinded, serde::Serialize)]
+        #[serde(rename_all = "snake_case")]
+        #[kinded(display = serde)]
+        enum Drink {
+            HotMate,
+        }
+
+        assert_eq!(DrinkKind::HotMate.to_string(), "hot_mate");
+    }
+}
+
+mod display_variant {
+    extern crate alloc;
+    use alloc::string::ToString;
+
+    #[derive(kinded::Kinded)]
+    #[kinded(display = "snake_case")]
+    enum Level {
+        #[kinded(display = "SCREAMING_SNAKE_CASE")]
+        VeryHigh,
+        #[kinded(display = "SCREAMING_SNAKE_CASE", rename = "mid")]
+        MiddleGround,
+        VeryLow,
+    }
+
+    #[test]
+    fn should_override_display_case_of_variant() {
+        assert_eq!(LevelKind::VeryHigh.to_string(), "VERY_HIGH");
+        assert_eq!(LevelKind::VeryLow.to_string(), "very_low");
+    }
+
+    #[test]
+    fn should_prefer_rename_over_variant_display_case() {
+        assert_eq!(LevelKind::MiddleGround.to_string(), "mid");
+    }
+
+    #[test]
+    fn should_parse_variant_display_case() {
+        assert_eq!(
+            "VERY_HIGH".parse::<LevelKind>().unwrap(),
+            LevelKind::VeryHigh
+        );
+        assert_eq!(
+            "VeryHigh".parse::<LevelKind>().unwrap(),
+            LevelKind::VeryHigh
+        );
+        assert_eq!("very_low".parse::<LevelKind>().unwrap(), LevelKind::VeryLow);
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde_traits {
+    #[derive(kinded::Kinded)]
+    #[kinded(display = "snake_case", serde)]
+    enum Drink {
+        VeryHotBlackTea,
+        Mate,
+    }
+
+    #[test]
+    fn should_serialize_as_display_name() {
+        let json = serde_json::to_string(&DrinkKind::VeryHotBlackTea).unwrap();
+        assert_eq!(json, r#""very_hot_black_tea""#);
+    }
+
+    #[test]
+    fn should_deserialize_from_display_name() {
+        let kind: DrinkKind = serde_json::from_str(r#""very_hot_black_tea""#).unwrap();
+        assert_eq!(kind, DrinkKind::VeryHotBlackTea);
+    }
+
+    #[test]
+    fn should_round_trip() {
+        for kind in DrinkKind::all() {
+            let json = serde_json::to_string(kind).unwrap();
+            let parsed: DrinkKind = serde_json::from_str(&json).unwrap();
+            assert_eq!(&parsed, kind);
+        }
+    }
+
+    #[test]
+    fn should_fail_to_deserialize_unknown_name() {
+        extern crate alloc;
+        use alloc::string::ToString;
+
+        let error = serde_json::from_str::<DrinkKind>(r#""calabaza""#).unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with(r#"Failed to parse "calabaza" as DrinkKind"#));
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde_repr_traits {
+    #[derive(kinded::Kinded)]
+    #[kinded(serde_repr)]
+    enum Drink {
+        Mate,
+        Coffee(&'static str),
+        Tea { variety: &'static str },
+    }
+
+    #[test]
+    fn should_serialize_as_index() {
+        assert_eq!(serde_json::to_string(&DrinkKind::Mate).unwrap(), "0");
+        assert_eq!(serde_json::to_string(&DrinkKind::Tea).unwrap(), "2");
+    }
+
+    #[test]
+    fn should_deserialize_from_index() {
+        let kind: DrinkKind = serde_json::from_str("1").unwrap();
+        assert_eq!(kind, DrinkKind::Coffee);
+    }
+
+    #[test]
+    fn should_round_trip() {
+        for kind in DrinkKind::all() {
+            let json = serde_json::to_string(kind).unwrap();
+            let parsed: DrinkKind = serde_json::from_str(&json).unwrap();
+            assert_eq!(&parsed, kind);
+        }
+    }
+
+    #[test]
+    fn should_fail_to_deserialize_unknown_index() {
+        extern crate alloc;
+        use alloc::string::ToString;
+
+        let error = serde_json::from_str::<DrinkKind>("3").unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with("invalid value: integer `3`, expected a kind index less than 3"));
+
+        assert!(serde_json::from_str::<DrinkKind>(r#""mate""#).is_err());
+    }
+}
+
+#[test]
+fn should_allow_to_specify_repr() {
+    #[derive(Kinded)]
+    #[kinded(repr = u8)]
+    enum Drink {
+        Mate,
+        Coffee(&'static str),
+        Tea { caffeine: bool },
+    }
+
+    assert_eq!(core::mem::size_of::<DrinkKind>(), 1);
+    assert_eq!(DrinkKind::Mate as u8, 0);
+    assert_eq!(DrinkKind::Tea as u8, 2);
+}
+
+mod stable_ids {
+    mod v1 {
+        #[derive(kinded::Kinded)]
+        pub enum Drink {
+            #[kinded(id = 1)]
+            Mate,
+            #[kinded(id = 2)]
+            Coffee(&'static str),
+            #[kinded(skip)]
+            Water,
+        }
+    }
+
+    mod v2 {
+        // Variants are reordered and a new one is added
+        #[derive(kinded::Kinded)]
+        pub enum Drink {
+            #[kinded(id = 3)]
+            Tea,
+            #[kinded(id = 2)]
+            Coffee(&'static str),
+            #[kinded(id = 1)]
+            Mate,
+        }
+    }
+
+    #[test]
+    fn should_keep_stable_ids_after_reordering() {
+        assert_eq!(
+            v1::DrinkKind::Mate.stable_id(),
+            v2::DrinkKind::Mate.stable_id()
+        );
+        assert_eq!(
+            v1::DrinkKind::Coffee.stable_id(),
+            v2::DrinkKind::Coffee.stable_id()
+        );
+        assert_ne!(
+            v1::DrinkKind::Mate.to_index(),
+            v2::DrinkKind::Mate.to_index()
+        );
+    }
+
+    #[test]
+    fn should_convert_stable_id_back() {
+        let id = v1::DrinkKind::Coffee.stable_id();
+        assert_eq!(
+            v2::DrinkKind::from_stable_id(id),
+            Some(v2::DrinkKind::Coffee)
+        );
+        assert_eq!(v1::DrinkKind::from_stable_id(3), None);
+    }
+}
+
+mod fast_kind {
+    use kinded::Kinded;
+
+    // The same enums, with and without `fast_kind`, to compare with the match-based kind
+    macro_rules! define_drink {
+        ($name:ident, $repr:ident $(, $attr:ident)?) => {
+            #[derive(Kinded)]
+            #[kinded($($attr)?)]
+            #[repr($repr)]
+            enum $name {
+                Mate,
+                Coffee(&'static str),
+                Tea { variety: &'static str, caffeine: bool },
+                Water,
+            }
+        };
+    }
+
+    define_drink!(Drink, u8);
+    define_drink!(FastDrink, u8, fast_kind);
+    define_drink!(WideFastDrink, i64, fast_kind);
+
+    #[test]
+    fn should_match_match_based_kind() {
+        let drinks = [
+            Drink::Mate,
+            Drink::Coffee("Espresso"),
+            Drink::Tea {
+                variety: "Sencha",
+                caffeine: true,
+            },
+            Drink::Water,
+        ];
+        let fast_drinks = [
+            FastDrink::Mate,
+            FastDrink::Coffee("Espresso"),
+            FastDrink::Tea {
+                variety: "Sencha",
+                caffeine: true,
+            },
+            FastDrink::Water,
+        ];
+        for (drink, fast_drink) in drinks.iter().zip(&fast_drinks) {
+            assert_eq!(drink.kind().to_index(), fast_drink.kind().to_index());
+            assert_eq!(drink.kind().as_str(), fast_drink.kind().as_str());
+        }
+    }
+
+    #[test]
+    fn should_support_any_integer_repr() {
+        assert_eq!(WideFastDrink::Mate.kind(), WideFastDrinkKind::Mate);
+        assert_eq!(
+            WideFastDrink::Coffee("Latte").kind(),
+            WideFastDrinkKind::Coffee
+        );
+        assert_eq!(WideFastDrink::Water.kind(), WideFastDrinkKind::Water);
+    }
+
+    #[test]
+    fn should_be_const() {
+        const KIND: FastDrinkKind = FastDrink::Coffee("Latte").kind();
+        assert_eq!(KIND, FastDrinkKind::Coffee);
+    }
+}
+
+mod discriminants {
+    use super::*;
+
+    #[test]
+    fn should_preserve_explicit_discriminants() {
+        #[derive(Kinded)]
+        #[repr(u16)]
+        enum Code {
+            Ok = 200,
+            NotFound = 404,
+            Teapot = 418,
+        }
+
+        assert_eq!(core::mem::size_of::<CodeKind>(), 2);
+        assert_eq!(CodeKind::Ok as u16, 200);
+        assert_eq!(CodeKind::NotFound as u16, 404);
+        assert_eq!(Code::Teapot.kind() as u16, Code::Teapot as u16);
+    }
+
+    #[test]
+    fn should_preserve_implicit_discriminants_after_skipped_variant() {
+        #[derive(Kinded)]
+        enum Level {
+            Low = 10,
+            #[kinded(skip)]
+            Unknown,
+            Medium,
+            High = 100,
+            Extreme,
+        }
+
+        assert_eq!(LevelKind::Low as isize, 10);
+        assert_eq!(LevelKind::Medium as isize, Level::Medium as isize);
+        assert_eq!(LevelKind::Medium as isize, 12);
+        assert_eq!(LevelKind::Extreme as isize, 101);
+    }
+
+    #[test]
+    fn should_not_count_variants_removed_by_cfg() {
+        #[derive(Kinded)]
+        enum Level {
+            Low = 10,
+            #[cfg(any())]
+            Unknown,
+            Medium,
+            #[cfg(test)]
+            High,
+            #[cfg(any())]
+            Critical = 50,
+            Extreme,
+        }
+
+        assert_eq!(LevelKind::Medium as isize, Level::Medium as isize);
+        assert_eq!(LevelKind::Medium as isize, 11);
+        assert_eq!(LevelKind::High as isize, 12);
+        assert_eq!(LevelKind::Extreme as isize, Level::Extreme as isize);
+        assert_eq!(LevelKind::Extreme as isize, 13);
+    }
+
+    #[test]
+    fn should_preserve_discriminants_of_variants_with_fields() {
+        #[derive(Kinded)]
+        #[repr(C, u8)]
+        enum Packet {
+            Ping = 1,
+            Data(u32) = 7,
+        }
+
+        assert_eq!(PacketKind::Ping as u8, 1);
+        assert_eq!(Packet::Data(5).kind() as u8, 7);
+    }
+
+    #[test]
+    fn should_order_by_discriminants_not_by_declaration() {
+        #[derive(Kinded)]
+        #[kinded(derive(PartialOrd, Ord))]
+        #[repr(u16)]
+        enum Code {
+            NotFound = 404,
+            Ok = 200,
+        }
+
+        let mut kinds = CodeKind::ALL;
+        kinds.sort();
+        assert_eq!(kinds, [CodeKind::Ok, CodeKind::NotFound]);
+        assert_eq!(CodeKind::NotFound.to_index(), 0);
+    }
+
+    #[test]
+    fn should_prefer_kinded_repr() {
+        #[derive(Kinded)]
+        #[kinded(repr = u32)]
+        #[repr(u8)]
+        enum Code {
+            Ok = 200,
+        }
+
+        assert_eq!(core::mem::size_of::<CodeKind>(), 4);
+        assert_eq!(CodeKind::Ok as u32, 200);
+    }
+}
+
+mod custom_visibility {
+    mod drinks {
+        #[derive(kinded::Kinded)]
+        #[kinded(vis = pub)]
+        enum Drink {
+            Mate,
+            Tea,
+        }
+
+        pub fn favorite() -> DrinkKind {
+            Drink::Mate.kind()
+        }
+    }
+
+    #[test]
+    fn should_allow_to_make_kind_type_more_visible_than_enum() {
+        assert_eq!(drinks::favorite(), drinks::DrinkKind::Mate);
+        assert_eq!(drinks::DrinkKind::Tea.as_str(), "Tea");
+    }
+}
+
+/// Used by `tests/ui/non_exhaustive_match.rs`, because `#[non_exhaustive]` has effect only
+/// in other crates.
+pub mod non_exhaustive {
+    #[derive(kinded::Kinded)]
+    #[non_exhaustive]
+    pub enum Drink {
+        Mate,
+        Tea,
+    }
+
+    #[derive(kinded::Kinded)]
+    #[kinded(non_exhaustive)]
+    pub enum Meal {
+        Breakfast,
+        Dinner,
+    }
+
+    #[test]
+    fn should_match_exhaustively_in_the_same_crate() {
+        let name = match DrinkKind::Mate {
+            DrinkKind::Mate => "mate",
+            DrinkKind::Tea => "tea",
+        };
+        assert_eq!(name, "mate");
+    }
+}
+
+#[cfg(all(test, feature = "clap"))]
+mod clap_value_enum {
+    use clap::{Parser, ValueEnum};
+
+    #[derive(kinded::Kinded)]
+    #[kinded(display = "snake_case", clap)]
+    enum Drink {
+        VeryHotBlackTea,
+        #[kinded(skip)]
+        Poison,
+        Mate,
+    }
+
+    #[derive(Parser)]
+    struct Cli {
+        #[arg(long, value_enum)]
+        drink: DrinkKind,
+    }
+
+    #[test]
+    fn should_parse_display_name() {
+        let cli = Cli::try_parse_from(["cli", "--drink", "very_hot_black_tea"]).unwrap();
+        assert_eq!(cli.drink, DrinkKind::VeryHotBlackTea);
+    }
+
+    #[test]
+    fn should_reject_unknown_name() {
+        assert!(Cli::try_parse_from(["cli", "--drink", "poison"]).is_err());
+    }
+
+    #[test]
+    fn should_provide_all_kinds_as_value_variants() {
+        assert_eq!(DrinkKind::value_variants(), DrinkKind::all());
+        let mate = DrinkKind::Mate.to_possible_value().unwrap();
+        assert_eq!(mate.get_name(), "mate");
+    }
+}
+
+#[cfg(all(test, feature = "strum"))]
+mod strum_traits {
+    use core::str::FromStr;
+    use strum::{EnumCount, IntoEnumIterator, VariantNames};
+
+    #[derive(kinded::Kinded)]
+    #[kinded(display = "kebab-case", strum)]
+    enum Drink {
+        VeryHotBlackTea,
+        #[kinded(skip)]
+        Poison,
+        Mate,
+    }
+
+    #[test]
+    fn should_convert_into_static_str() {
+        let name: &'static str = DrinkKind::VeryHotBlackTea.into();
+        assert_eq!(name, "very-hot-black-tea");
+    }
+
+    #[test]
+    fn should_parse_from_str() {
+        fn parse<T: FromStr>(s: &str) -> Option<T> {
+            s.parse().ok()
+        }
+
+        assert_eq!(
+            parse("very-hot-black-tea"),
+            Some(DrinkKind::VeryHotBlackTea)
+        );
+        assert_eq!(parse::<DrinkKind>("poison"), None);
+    }
+
+    #[test]
+    fn should_implement_strum_traits() {
+        fn names<T: VariantNames>() -> &'static [&'static str] {
+            T::VARIANTS
+        }
+
+        assert_eq!(<DrinkKind as EnumCount>::COUNT, 2);
+        assert_eq!(names::<DrinkKind>(), ["very-hot-black-tea", "mate"]);
+        assert!(<DrinkKind as IntoEnumIterator>::iter()
+            .eq([DrinkKind::VeryHotBlackTea, DrinkKind::Mate]));
+    }
+}
+
+#[test]
+fn should_allow_to_give_custom_name_kind_type() {
+    #[derive(Kinded)]
+    #[kinded(kind = SimpleDrink)]
+    enum Drink {
+        Tea(&'static str),
+        Coffee(&'static str),
+    }
+
+    let green_tea = Drink::Tea("Green");
+    assert_eq!(green_tea.kind(), SimpleDrink::Tea);
+}
+
+mod kind_name_affixes {
+    use super::*;
+
+    #[test]
+    fn should_allow_custom_suffix() {
+        #[derive(Kinded)]
+        #[kinded(suffix = "Tag")]
+        enum Drink {
+            Mate,
+        }
+
+        assert_eq!(Drink::Mate.kind(), DrinkTag::Mate);
+    }
+
+    #[test]
+    fn should_allow_custom_prefix() {
+        #[derive(Kinded)]
+        #[kinded(prefix = "K")]
+        enum Drink {
+            Mate,
+        }
+
+        assert_eq!(Drink::Mate.kind(), KDrink::Mate);
+    }
+
+    #[test]
+    fn should_allow_custom_prefix_and_suffix() {
+        #[derive(Kinded)]
+        #[kinded(prefix = "Plain", suffix = "Tag")]
+        enum Drink {
+            Mate,
+        }
+
+        assert_eq!(Drink::Mate.kind(), PlainDrinkTag::Mate);
+    }
+
+    #[test]
+    fn should_prefer_explicit_kind_name() {
+        #[derive(Kinded)]
+        #[kinded(kind = SimpleDrink, suffix = "Tag")]
+        enum Drink {
+            Mate,
+        }
+
+        assert_eq!(Drink::Mate.kind(), SimpleDrink::Mate);
+    }
+}
+
+mod histogram {
+    use kinded::Kinded;
+
+    #[derive(Kinded)]
+    #[kinded(histogram)]
+    enum Drink {
+        Mate,
+        Coffee(&'static str),
+        Tea,
+    }
+
+    #[test]
+    fn should_build_histogram_from_iterator() {
+        let drinks = [
+            Drink::Coffee("Espresso"),
+            Drink::Mate,
+            Drink::Coffee("Latte"),
+        ];
+        let histogram: DrinkKindHistogram = drinks.iter().map(Drink::kind).collect();
+        assert_eq!(histogram.get(DrinkKind::Mate), 1);
+        assert_eq!(histogram.get(DrinkKind::Coffee), 2);
+        assert_eq!(histogram.get(DrinkKind::Tea), 0);
+        assert_eq!(histogram.total(), 3);
+    }
+
+    #[test]
+    fn should_increment_and_extend() {
+        let mut histogram = DrinkKindHistogram::new();
+        assert_eq!(histogram, DrinkKindHistogram::default());
+
+        histogram.increment(DrinkKind::Tea);
+        histogram.extend([DrinkKind::Tea, DrinkKind::Mate]);
+        assert_eq!(histogram.get(DrinkKind::Tea), 2);
+        assert_eq!(histogram.get(DrinkKind::Mate), 1);
+    }
+
+    #[test]
+    fn should_iterate_in_declaration_order() {
+        let histogram: DrinkKindHistogram = [DrinkKind::Tea, DrinkKind::Mate].into_iter().collect();
+        let mut counts = histogram.iter();
+        assert_eq!(counts.next(), Some((DrinkKind::Mate, 1)));
+        assert_eq!(counts.next(), Some((DrinkKind::Coffee, 0)));
+        assert_eq!(counts.next(), Some((DrinkKind::Tea, 1)));
+        assert_eq!(counts.next(), None);
+    }
+}
+
+mod kind_set {
+    use kinded::Kinded;
+
+    #[derive(Kinded)]
+    #[kinded(set)]
+    enum Drink {
+        Mate,
+        Coffee(&'static str),
+        Tea,
+        Water,
+    }
+
+    #[test]
+    fn should_insert_and_remove() {
+        let mut set = DrinkKindSet::new();
+        assert_eq!(set, DrinkKindSet::default());
+        assert!(set.is_empty());
+
+        assert!(set.insert(DrinkKind::Tea));
+        assert!(!set.insert(DrinkKind::Tea));
+        assert!(set.insert(DrinkKind::Mate));
+        assert!(set.contains(DrinkKind::Tea));
+        assert!(set.contains(DrinkKind::Mate));
+        assert!(!set.contains(DrinkKind::Coffee));
+        assert_eq!(set.len(), 2);
+
+        assert!(set.remove(DrinkKind::Tea));
+        assert!(!set.remove(DrinkKind::Tea));
+        assert!(!set.contains(DrinkKind::Tea));
+        assert_eq!(set.len(), 1);
+    }
+
+    #[test]
+    fn should_combine_sets() {
+        let hot: DrinkKindSet = [DrinkKind::Mate, DrinkKind::Coffee, DrinkKind::Tea]
+            .into_iter()
+            .collect();
+        let caffeinated: DrinkKindSet = [DrinkKind::Coffee, DrinkKind::Mate].into_iter().collect();
+        let cold: DrinkKindSet = [DrinkKind::Water].into_iter().collect();
+
+        assert_eq!(hot.intersection(caffeinated), caffeinated);
+        assert_eq!(hot.intersection(cold), DrinkKindSet::new());
+        assert_eq!(hot.union(cold).len(), DrinkKind::COUNT);
+        assert_eq!(caffeinated.union(caffeinated), caffeinated);
+    }
+
+    #[test]
+    fn should_iterate_in_declaration_order() {
+        extern crate alloc;
+        use alloc::{format, vec::Vec};
+
+        let mut set = DrinkKindSet::new();
+        set.extend([DrinkKind::Water, DrinkKind::Mate, DrinkKind::Tea]);
+        let kinds: Vec<DrinkKind> = set.iter().collect();
+        assert_eq!(kinds, [DrinkKind::Mate, DrinkKind::Tea, DrinkKind::Water]);
+        assert_eq!(format!("{set:?}"), "{Mate, Tea, Water}");
+    }
+
+    #[test]
+    fn should_be_const() {
+        const EMPTY: DrinkKindSet = DrinkKindSet::new().union(DrinkKindSet::new());
+        const LEN: usize = EMPTY.len();
+        assert_eq!(LEN, 0);
+    }
+
+    #[test]
+    fn should_hold_64_kinds() {
+        macro_rules! define_wide {
+            ($($variant:ident),*) => {
+                #[derive(Kinded)]
+                #[kinded(set)]
+                enum Wide {
+                    $($variant),*
+                }
+            };
+        }
+
+        define_wide!(
+            V00, V01, V02, V03, V04, V05, V06, V07, V08, V09, V10, V11, V12, V13, V14, V15, V16,
+            V17, V18, V19, V20, V21, V22, V23, V24, V25, V26, V27, V28, V29, V30, V31, V32, V33,
+            V34, V35, V36, V37, V38, V39, V40, V41, V42, V43, V44, V45, V46, V47, V48, V49, V50,
+            V51, V52, V53, V54, V55, V56, V57, V58, V59, V60, V61, V62, V63
+        );
+
+        let mut set = WideKindSet::new();
+        set.insert(WideKind::V63);
+        set.insert(WideKind::V00);
+        assert!(set.contains(WideKind::V63));
+        assert_eq!(set.iter().last(), Some(WideKind::V63));
+        assert_eq!(set.len(), 2);
+    }
+}
+
+mod all_order {
+    use kinded::Kinded;
+
+    #[derive(Kinded)]
+    #[kinded(all_order = "name", display = "snake_case", histogram)]
+    enum Drink {
+        Tea,
+        Coffee(&'static str),
+        Mate,
+        AppleJuice,
+    }
+
     #[test]
-    fn should_provide_all_function_that_returns_iterator() {
-        fn impl_iter(_: impl IntoIterator<Item = &'static RoleKind>) {}
-        impl_iter(RoleKind::all());
+    fn should_sort_all_by_display_names() {
+        let names: [&str; 4] = DrinkKind::ALL.map(|kind| kind.as_str());
+        assert_eq!(names, ["apple_juice", "coffee", "mate", "tea"]);
+        assert_eq!(
+            DrinkKind::ALL,
+            [
+                DrinkKind::AppleJuice,
+                DrinkKind::Coffee,
+                DrinkKind::Mate,
+                DrinkKind::Tea
+            ]
+        );
+        assert_eq!(<DrinkKind as kinded::Kind>::all(), DrinkKind::ALL);
+    }
+
+    #[test]
+    fn should_iterate_in_the_order_of_all() {
+        let mut named = DrinkKind::all_named();
+        assert_eq!(named.next(), Some((DrinkKind::AppleJuice, "apple_juice")));
+        assert_eq!(named.next(), Some((DrinkKind::Coffee, "coffee")));
+
+        let histogram: DrinkKindHistogram = [DrinkKind::Tea, DrinkKind::Tea].into_iter().collect();
+        assert_eq!(histogram.iter().next(), Some((DrinkKind::AppleJuice, 0)));
+        assert_eq!(histogram.iter().last(), Some((DrinkKind::Tea, 2)));
+    }
+
+    #[test]
+    fn should_keep_indexes_in_declaration_order() {
+        assert_eq!(DrinkKind::NAMES, ["tea", "coffee", "mate", "apple_juice"]);
+        assert_eq!(DrinkKind::Tea.to_index(), 0);
+        assert_eq!(DrinkKind::from_index(0), Some(DrinkKind::Tea));
+        assert_eq!(DrinkKind::Tea.next(), DrinkKind::Coffee);
+        assert_eq!(DrinkKind::AppleJuice.next(), DrinkKind::Tea);
+        assert_eq!(DrinkKind::Tea.prev(), DrinkKind::AppleJuice);
+        assert_eq!(DrinkKind::by_name("mate"), Some(DrinkKind::Mate));
+
+        let mut range = DrinkKind::range(DrinkKind::Coffee..);
+        assert_eq!(range.next(), Some(DrinkKind::Coffee));
+        assert_eq!(range.next(), Some(DrinkKind::Mate));
+        assert_eq!(range.next(), Some(DrinkKind::AppleJuice));
+        assert_eq!(range.next(), None);
+    }
+
+    #[test]
+    fn should_keep_declaration_order_by_default() {
+        #[derive(Kinded)]
+        #[kinded(all_order = "declaration")]
+        enum Drink {
+            Tea,
+            Coffee,
+        }
+
+        assert_eq!(DrinkKind::all(), [DrinkKind::Tea, DrinkKind::Coffee]);
+    }
+}
+
+mod kind_method {
+    use kinded::Kinded;
+
+    #[derive(Kinded)]
+    #[kinded(method = category, eq_original)]
+    enum Drink {
+        Mate,
+        Coffee(&'static str),
+    }
+
+    #[test]
+    fn should_rename_inherent_method() {
+        assert_eq!(Drink::Coffee("Latte").category(), DrinkKind::Coffee);
+        assert_eq!(Drink::Mate.category(), DrinkKind::Mate);
+    }
+
+    #[test]
+    fn should_keep_trait_method() {
+        fn compute_kind<T: Kinded>(value: &T) -> T::Kind {
+            value.kind()
+        }
+
+        assert_eq!(compute_kind(&Drink::Mate), DrinkKind::Mate);
+        assert_eq!(Drink::Coffee("Latte").kind(), DrinkKind::Coffee);
+    }
+
+    #[test]
+    fn should_use_renamed_method_in_generated_code() {
+        assert!(Drink::Mate.is_kind(DrinkKind::Mate));
+        assert_eq!(Drink::Coffee("Latte").kind_index(), 1);
+        assert_eq!(DrinkKind::from(&Drink::Mate), DrinkKind::Mate);
+        assert!(Drink::Mate == DrinkKind::Mate);
+    }
+}
+
+mod variant_prefix {
+    use kinded::Kinded;
+
+    #[derive(Kinded)]
+    #[kinded(variant_prefix = "K", default = Tea)]
+    enum Drink {
+        Mate,
+        Coffee(&'static str),
+        Tea { variety: &'static str },
+    }
+
+    #[test]
+    fn should_prefix_kind_variants() {
+        assert_eq!(Drink::Mate.kind(), DrinkKind::KMate);
+        assert_eq!(Drink::Coffee("Espresso").kind(), DrinkKind::KCoffee);
+        assert_eq!(DrinkKind::default(), DrinkKind::KTea);
+    }
+
+    #[test]
+    fn should_import_variants_by_glob_without_collisions() {
+        use Drink::*;
+        use DrinkKind::*;
+
+        assert_eq!(Mate.kind(), KMate);
+        assert_eq!(Coffee("Latte").kind(), KCoffee);
+    }
+
+    #[test]
+    fn should_display_original_names() {
+        extern crate alloc;
+        use alloc::string::ToString;
+
+        assert_eq!(DrinkKind::KMate.to_string(), "Mate");
+        assert_eq!("Mate".parse::<DrinkKind>().unwrap(), DrinkKind::KMate);
+    }
+
+    #[test]
+    fn should_prefix_struct_kind_variant() {
+        #[derive(Kinded)]
+        #[kinded(variant_prefix = "K")]
+        struct Coffee;
+
+        assert_eq!(Coffee.kind(), CoffeeKind::KCoffee);
+        assert_eq!(
+            Coffee::from(CoffeeKind::KCoffee).kind(),
+            CoffeeKind::KCoffee
+        );
     }
 }
 
 #[test]
-fn should_allow_to_give_custom_name_kind_type() {
+#[allow(dead_code, unused_imports)]
+fn should_not_be_affected_by_shadowed_names() {
+    // Derive macro of Kinded imported as Debug would be picked up by #[derive(Debug)]
+    use kinded::Kinded as Debug;
+
+    struct Result;
+    struct Option;
+    struct Some;
+    struct None;
+    struct Ok;
+    struct Err;
+    trait From {}
+    trait PartialEq {}
+    mod core {}
+
+    #[derive(Kinded)]
+    #[kinded(display = "snake_case", eq_original, display_original)]
+    enum Drink {
+        Mate,
+        HotCoffee(&'static str),
+        #[kinded(skip)]
+        Water,
+    }
+
+    assert_eq!(Drink::HotCoffee("Espresso").kind(), DrinkKind::HotCoffee);
+    assert_eq!(
+        "hot_coffee".parse::<DrinkKind>().unwrap(),
+        DrinkKind::HotCoffee
+    );
+    assert!("cola".parse::<DrinkKind>().is_err());
+    assert_eq!(
+        DrinkKind::Mate.checked_next(),
+        ::core::option::Option::Some(DrinkKind::HotCoffee)
+    );
+}
+
+#[test]
+fn should_allow_fields_of_types_named_like_the_kind() {
+    mod menu {
+        #[derive(Debug, Default, PartialEq)]
+        pub struct DrinkKind(pub &'static str);
+    }
+
+    #[derive(Kinded, Debug, PartialEq)]
+    #[kinded(try_into_original, eq_original)]
+    enum Drink {
+        Mate,
+        Coffee(menu::DrinkKind),
+        Tea { kind: menu::DrinkKind },
+    }
+
+    let coffee = Drink::Coffee(menu::DrinkKind("Espresso"));
+    assert_eq!(coffee.kind(), DrinkKind::Coffee);
+    assert!(coffee == DrinkKind::Coffee);
+    assert_eq!(
+        Drink::Tea {
+            kind: menu::DrinkKind("Sencha")
+        }
+        .kind(),
+        DrinkKind::Tea
+    );
+    assert_eq!(
+        Drink::try_from(DrinkKind::Tea),
+        Ok(Drink::Tea {
+            kind: menu::DrinkKind::default()
+        })
+    );
+}
+
+#[test]
+fn should_merge_multiple_kinded_attributes() {
     #[derive(Kinded)]
     #[kinded(kind = SimpleDrink)]
+    #[kinded(derive(PartialOrd, Ord))]
+    #[kinded(display = "snake_case")]
     enum Drink {
-        Tea(&'static str),
+        GreenTea(&'static str),
+        #[kinded(rename = "espresso")]
+        #[kinded(skip)]
         Coffee(&'static str),
     }
 
-    let green_tea = Drink::Tea("Green");
-    assert_eq!(green_tea.kind(), SimpleDrink::Tea);
+    assert_eq!(Drink::GreenTea("Sencha").kind(), SimpleDrink::GreenTea);
+    assert_eq!(
+        SimpleDrink::GreenTea.cmp(&SimpleDrink::GreenTea),
+        core::cmp::Ordering::Equal
+    );
+    assert_eq!(SimpleDrink::GreenTea.as_str(), "green_tea");
+    assert_eq!(SimpleDrink::COUNT, 1);
 }
 
 #[test]
@@ -330,6 +3019,236 @@ fn should_allow_to_derive_custom_traits() {
     drinks.insert(DrinkKind::Tea, 5);
 }
 
+#[test]
+fn should_ignore_derives_provided_by_default() {
+    extern crate alloc;
+    use alloc::collections::BTreeSet;
+
+    #[derive(Kinded)]
+    #[kinded(derive(Debug, Hash, core::clone::Clone, PartialOrd, Ord))]
+    enum Drink {
+        Tea(&'static str),
+        Coffee(&'static str),
+    }
+
+    let set: BTreeSet<DrinkKind> = [DrinkKind::Coffee, DrinkKind::Tea].into();
+    assert_eq!(set.len(), 2);
+}
+
+#[test]
+fn should_not_derive_removed_default_traits() {
+    use core::hash::{Hash, Hasher};
+
+    #[derive(Kinded)]
+    #[kinded(no_derive(Hash))]
+    enum Drink {
+        Tea(&'static str),
+        Coffee(&'static str),
+    }
+
+    // Would conflict with the derived implementation
+    impl Hash for DrinkKind {
+        fn hash<H: Hasher>(&self, state: &mut H) {
+            self.as_str().hash(state);
+        }
+    }
+
+    assert_eq!(Drink::Tea("Sencha").kind(), DrinkKind::Tea);
+}
+
+#[test]
+fn should_generate_kind_without_copy() {
+    extern crate alloc;
+    use alloc::string::ToString;
+
+    #[derive(Kinded)]
+    #[kinded(no_derive(Copy), display = "snake_case")]
+    enum Drink {
+        Tea(&'static str),
+        IcedCoffee(&'static str),
+    }
+
+    let kind = Drink::Tea("Sencha").kind();
+    assert_eq!(kind.clone(), DrinkKind::Tea);
+    assert_ne!(kind, DrinkKind::IcedCoffee);
+
+    // The items taking the kind by reference are generated as usual
+    let drink = Drink::IcedCoffee("Espresso");
+    assert_eq!(<Drink as Kinded>::kind(&drink), DrinkKind::IcedCoffee);
+    assert_eq!(DrinkKind::from(&drink).as_str(), "iced_coffee");
+    assert_eq!(DrinkKind::IcedCoffee.to_string(), "iced_coffee");
+    assert_eq!(
+        "IcedCoffee".parse::<DrinkKind>().unwrap(),
+        DrinkKind::IcedCoffee
+    );
+    assert!(drink.is_kind(DrinkKind::IcedCoffee));
+    assert_eq!(
+        <DrinkKind as kinded::Kind>::all(),
+        [DrinkKind::Tea, DrinkKind::IcedCoffee]
+    );
+    assert!(DrinkKind::iter().eq([DrinkKind::Tea, DrinkKind::IcedCoffee]));
+}
+
+#[test]
+fn should_order_kinds_in_declaration_order() {
+    extern crate alloc;
+    use alloc::{collections::BTreeSet, vec, vec::Vec};
+
+    #[derive(Kinded)]
+    #[kinded(derive(PartialOrd, Ord))]
+    enum Drink {
+        Tea,
+        Coffee,
+        Mate,
+    }
+
+    let mut kinds = vec![DrinkKind::Mate, DrinkKind::Tea, DrinkKind::Coffee];
+    kinds.sort();
+    assert_eq!(kinds, [DrinkKind::Tea, DrinkKind::Coffee, DrinkKind::Mate]);
+
+    let set: BTreeSet<DrinkKind> = [DrinkKind::Mate, DrinkKind::Coffee, DrinkKind::Tea].into();
+    let ordered: Vec<DrinkKind> = set.into_iter().collect();
+    assert_eq!(ordered, DrinkKind::all());
+}
+
+mod external_kind {
+    use kinded::{Kind, Kinded};
+
+    /// Handwritten kind type, shared by several enums and with an extra variant.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    enum BeverageKind {
+        Mate,
+        Coffee,
+        Tea,
+        Water,
+    }
+
+    impl Kind for BeverageKind {
+        fn all() -> &'static [Self] {
+            &[Self::Mate, Self::Coffee, Self::Tea, Self::Water]
+        }
+    }
+
+    #[derive(Kinded)]
+    #[kinded(kind = BeverageKind, external)]
+    enum Drink {
+        Mate,
+        Coffee(&'static str),
+        Tea { variety: &'static str },
+    }
+
+    #[derive(Kinded)]
+    #[kinded(kind = BeverageKind, external, method = beverage)]
+    enum Bottle {
+        Water(u32),
+        #[kinded(skip)]
+        Empty,
+    }
+
+    #[test]
+    fn should_map_to_existing_kind_variants() {
+        assert_eq!(Drink::Mate.kind(), BeverageKind::Mate);
+        assert_eq!(Drink::Coffee("Espresso").kind(), BeverageKind::Coffee);
+        assert_eq!(Drink::Tea { variety: "Green" }.kind(), BeverageKind::Tea);
+        assert_eq!(Bottle::Water(500).beverage(), BeverageKind::Water);
+    }
+
+    #[test]
+    fn should_implement_kinded_trait() {
+        fn compute_kind<T: Kinded>(value: &T) -> T::Kind {
+            value.kind()
+        }
+
+        assert_eq!(compute_kind(&Drink::Mate), BeverageKind::Mate);
+        assert_eq!(compute_kind(&Bottle::Water(330)), BeverageKind::Water);
+    }
+
+    #[test]
+    fn should_compare_and_consume_kind() {
+        assert!(Drink::Coffee("Latte").is_kind(BeverageKind::Coffee));
+        assert!(!Drink::Mate.is_kind(BeverageKind::Water));
+        assert_eq!(Bottle::Water(1).into_kind(), BeverageKind::Water);
+    }
+}
+
+mod structs {
+    use kinded::Kinded;
+
+    #[derive(Kinded)]
+    struct Mate;
+
+    #[derive(Kinded)]
+    struct Coffee(&'static str);
+
+    #[derive(Kinded)]
+    struct Tea {
+        caffeine: bool,
+    }
+
+    fn compute_kind<T: Kinded>(val: &T) -> <T as Kinded>::Kind {
+        val.kind()
+    }
+
+    #[test]
+    fn should_derive_kinded_on_unit_struct() {
+        assert_eq!(Mate.kind(), MateKind::Mate);
+        assert_eq!(MateKind::all(), [MateKind::Mate]);
+    }
+
+    #[test]
+    fn should_derive_kinded_on_tuple_struct() {
+        let coffee = Coffee("Espresso");
+        assert_eq!(compute_kind(&coffee), CoffeeKind::Coffee);
+        assert_eq!(CoffeeKind::from(coffee), CoffeeKind::Coffee);
+    }
+
+    #[test]
+    fn should_derive_kinded_on_struct_with_named_fields() {
+        let tea = Tea { caffeine: true };
+        assert_eq!(compute_kind(&tea), TeaKind::Tea);
+        assert_eq!(TeaKind::Tea.as_str(), "Tea");
+    }
+}
+
+#[test]
+fn should_allow_to_specify_default_kind() {
+    #[derive(Kinded)]
+    #[kinded(default = Mate)]
+    enum Drink {
+        Coffee(&'static str),
+        Mate,
+    }
+
+    assert_eq!(DrinkKind::default(), DrinkKind::Mate);
+}
+
+#[test]
+fn should_allow_to_mark_default_variant() {
+    #[derive(Kinded)]
+    enum Drink {
+        Coffee(&'static str),
+        #[kinded(default)]
+        Tea {
+            caffeine: bool,
+        },
+    }
+
+    assert_eq!(DrinkKind::default(), DrinkKind::Tea);
+}
+
+#[test]
+fn should_allow_to_forward_attributes_to_kind_type() {
+    #[derive(Kinded)]
+    #[kinded(attr(repr(u8), derive(PartialOrd)))]
+    enum Drink {
+        Mate,
+        Tea(&'static str),
+    }
+
+    assert_eq!(core::mem::size_of::<DrinkKind>(), 1);
+    assert!(DrinkKind::Mate < DrinkKind::Tea);
+}
+
 #[test]
 fn should_work_with_generics() {
     #[derive(Kinded)]
@@ -341,6 +3260,102 @@ fn should_work_with_generics() {
     assert_eq!(Maybe::Just(13).kind(), MaybeKind::Just);
 }
 
+#[test]
+fn should_work_with_generics_in_named_fields() {
+    #[derive(Kinded)]
+    enum Event<T> {
+        Tagged { data: T, id: u32 },
+        Empty,
+    }
+
+    fn kind_of<K: Kinded>(value: &K) -> K::Kind {
+        value.kind()
+    }
+
+    let event = Event::Tagged { data: "x", id: 1 };
+    assert_eq!(event.kind(), EventKind::Tagged);
+    assert_eq!(kind_of(&Event::<()>::Empty), EventKind::Empty);
+
+    // The kind type is not generic, so it is the same for all the instantiations
+    let kinds: [EventKind; 2] = [event.kind(), Event::<u8>::Empty.kind()];
+    assert_eq!(kinds, [EventKind::Tagged, EventKind::Empty]);
+}
+
+#[test]
+fn should_work_with_generics_in_tuple_fields() {
+    #[derive(Kinded)]
+    enum Pair<A, B> {
+        Both(A, B),
+        Left(A),
+        Right(B),
+    }
+
+    let pair: Pair<u8, &str> = Pair::Right("right");
+    assert_eq!(pair.kind(), PairKind::Right);
+    assert_eq!(PairKind::from(&Pair::<u8, u8>::Both(1, 2)), PairKind::Both);
+}
+
+#[test]
+fn should_work_with_const_generics_and_bounds() {
+    use alloc::vec::Vec;
+
+    #[derive(Kinded)]
+    enum Buffer<const N: usize, T: Copy> {
+        Inline([T; N]),
+        Heap(Vec<T>),
+    }
+
+    fn kind_of<K: Kinded>(value: &K) -> K::Kind {
+        value.kind()
+    }
+
+    let buffer: Buffer<2, u8> = Buffer::Inline([1, 2]);
+    assert_eq!(buffer.kind(), BufferKind::Inline);
+    assert_eq!(
+        kind_of(&Buffer::<4, u8>::Heap(Vec::new())),
+        BufferKind::Heap
+    );
+}
+
+#[test]
+fn should_work_with_where_clauses() {
+    use core::fmt::Debug;
+
+    #[derive(Kinded)]
+    #[kinded(display_original)]
+    enum Message<'a, T>
+    where
+        T: Debug + ?Sized,
+    {
+        Borrowed(&'a T),
+        Empty,
+    }
+
+    let message: Message<str> = Message::Borrowed("hello");
+    assert_eq!(message.kind(), MessageKind::Borrowed);
+    assert_eq!(MessageKind::from(&message), MessageKind::Borrowed);
+    assert_eq!(alloc::string::ToString::to_string(&message), "Borrowed");
+}
+
+#[test]
+fn should_keep_where_clause_on_kinded_impl() {
+    fn kind_of<T: Kinded>(value: &T) -> T::Kind {
+        value.kind()
+    }
+
+    #[derive(Kinded)]
+    enum Wrapper<T>
+    where
+        T: Clone,
+    {
+        A(T),
+        B,
+    }
+
+    assert_eq!(kind_of(&Wrapper::A(1)), WrapperKind::A);
+    assert_eq!(kind_of(&Wrapper::<u8>::B), WrapperKind::B);
+}
+
 #[test]
 fn should_work_with_lifetimes() {
     #[derive(Kinded)]
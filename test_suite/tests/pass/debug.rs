@@ -0,0 +1,14 @@
+//! `debug` attribute prints the generated code, but does not affect it.
+use kinded::Kinded;
+
+#[derive(Kinded)]
+#[kinded(debug, display = "snake_case")]
+enum Drink {
+    Mate,
+    Coffee(String),
+}
+
+fn main() {
+    assert_eq!(Drink::Coffee("Espresso".to_owned()).kind(), DrinkKind::Coffee);
+    assert_eq!(DrinkKind::Mate.to_string(), "mate");
+}
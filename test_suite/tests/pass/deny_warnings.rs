@@ -0,0 +1,43 @@
+#![deny(warnings, unused, clippy::all, clippy::pedantic)]
+
+use kinded::Kinded;
+
+#[derive(Kinded)]
+enum Drink {
+    Mate,
+    Coffee(String),
+    Tea { variety: String, caffeine: bool },
+}
+
+#[derive(Kinded)]
+#[kinded(display = "snake_case", eq_original, display_original, try_into_original)]
+enum Level {
+    LevelLow,
+    LevelMiddle,
+    LevelHigh,
+}
+
+#[derive(Kinded)]
+struct Unit;
+
+fn main() {
+    let drinks = [
+        Drink::Mate,
+        Drink::Coffee("Espresso".to_owned()),
+        Drink::Tea {
+            variety: "Green".to_owned(),
+            caffeine: true,
+        },
+    ];
+    for drink in &drinks {
+        match drink {
+            Drink::Mate => {}
+            Drink::Coffee(name) | Drink::Tea { variety: name, .. } => println!("{name}"),
+        }
+        if let Drink::Tea { caffeine, .. } = drink {
+            println!("{caffeine}");
+        }
+    }
+    let _ = [Level::LevelLow, Level::LevelMiddle, Level::LevelHigh];
+    let _ = Unit;
+}
@@ -0,0 +1,22 @@
+//! `#[doc(hidden)]` of a variant must be forwarded to the kind variant,
+//! otherwise `missing_docs` lint would fail on the undocumented kind variant.
+#![deny(missing_docs)]
+
+use kinded::Kinded;
+
+/// A drink.
+#[derive(Kinded)]
+pub enum Drink {
+    /// Yerba mate.
+    Mate,
+    #[doc(hidden)]
+    Secret(String),
+    #[doc(hidden, alias = "brew")]
+    Potion,
+}
+
+fn main() {
+    assert_eq!(Drink::Secret("recipe".to_owned()).kind(), DrinkKind::Secret);
+    assert!(DrinkKind::Potion.is_potion());
+    assert_eq!(DrinkKind::SECRET_INDEX, 1);
+}
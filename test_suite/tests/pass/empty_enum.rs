@@ -0,0 +1,21 @@
+//! An enum without variants gets a kind type without variants, and no warnings are emitted.
+#![deny(warnings)]
+
+use kinded::{Kind, Kinded};
+
+#[derive(Kinded)]
+#[kinded(derive(Hash, PartialOrd, Ord))]
+pub enum Never {}
+
+pub fn never_kind(never: &Never) -> NeverKind {
+    never.kind()
+}
+
+fn main() {
+    assert_eq!(NeverKind::COUNT, 0);
+    assert_eq!(NeverKind::ALL, [] as [NeverKind; 0]);
+    assert!(NeverKind::all().is_empty());
+    assert!(<NeverKind as Kind>::all().is_empty());
+    assert_eq!(NeverKind::from_index(0), None);
+    assert!("Anything".parse::<NeverKind>().is_err());
+}
@@ -0,0 +1,23 @@
+//! Generated kind variants must get the docs of the original variants,
+//! otherwise `missing_docs` lint would fail.
+#![deny(missing_docs)]
+
+use kinded::Kinded;
+
+/// A drink.
+#[derive(Kinded)]
+pub enum Drink {
+    /// Yerba mate.
+    Mate,
+    /// Coffee of some variety.
+    Coffee(String),
+    /// Tea of some variety.
+    Tea {
+        /// Variety of the tea.
+        variety: String,
+    },
+}
+
+fn main() {
+    assert_eq!(Drink::Mate.kind(), DrinkKind::Mate);
+}
@@ -0,0 +1,13 @@
+#[test]
+fn ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+    t.pass("tests/pass/*.rs");
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn ui_serde() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui_serde/*.rs");
+}
@@ -0,0 +1,10 @@
+use kinded::Kinded;
+
+#[derive(Kinded)]
+#[kinded(all_order = "alphabetical")]
+enum Drink {
+    Mate,
+    Coffee(String),
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use kinded::Kinded;
+
+#[derive(Kinded)]
+enum Drink {
+    #[kinded(default)]
+    Mate,
+    #[kinded(default)]
+    Coffee(String),
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use kinded::Kinded;
+
+#[derive(Kinded)]
+#[kinded(default = Poison)]
+enum Drink {
+    Mate,
+    #[kinded(skip)]
+    Poison,
+}
+
+fn main() {}
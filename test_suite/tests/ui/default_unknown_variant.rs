@@ -0,0 +1,10 @@
+use kinded::Kinded;
+
+#[derive(Kinded)]
+#[kinded(default = Mat)]
+enum Drink {
+    Mate,
+    Tea,
+}
+
+fn main() {}
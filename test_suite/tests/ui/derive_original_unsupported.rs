@@ -0,0 +1,10 @@
+use kinded::Kinded;
+
+#[derive(Kinded)]
+#[kinded(derive_original(Hash))]
+enum Drink {
+    Mate,
+    Coffee(String),
+}
+
+fn main() {}
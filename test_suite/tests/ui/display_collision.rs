@@ -0,0 +1,10 @@
+use kinded::Kinded;
+
+#[derive(Kinded)]
+#[kinded(display = "lowercase")]
+enum Db {
+    MySql,
+    MySQL,
+}
+
+fn main() {}
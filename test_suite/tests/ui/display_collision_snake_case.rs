@@ -0,0 +1,11 @@
+use kinded::Kinded;
+
+#[derive(Kinded)]
+#[kinded(display = "snake_case")]
+#[allow(non_camel_case_types)]
+enum Token {
+    FooBar,
+    Foo_Bar,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use kinded::Kinded;
+
+#[derive(Kinded)]
+#[kinded(display = "Sponge-Case")]
+enum Drink {
+    Mate,
+    Coffee,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use kinded::Kinded;
+
+#[derive(Kinded)]
+#[kinded(display = kebab-case)]
+enum Drink {
+    Mate,
+    Coffee(String),
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use kinded::Kinded;
+
+#[derive(Kinded)]
+#[kinded(kind = SimpleDrink)]
+#[kinded(kind = PlainDrink)]
+enum Drink {
+    Mate,
+    Coffee,
+}
+
+fn main() {}
@@ -0,0 +1,23 @@
+use kinded::{Kind, Kinded};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum BeverageKind {
+    Mate,
+    Coffee,
+}
+
+impl Kind for BeverageKind {
+    fn all() -> &'static [Self] {
+        &[Self::Mate, Self::Coffee]
+    }
+}
+
+#[derive(Kinded)]
+#[kinded(kind = BeverageKind, external)]
+enum Drink {
+    Mate,
+    Coffee(String),
+    Tea { variety: String },
+}
+
+fn main() {}
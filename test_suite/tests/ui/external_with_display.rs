@@ -0,0 +1,10 @@
+use kinded::Kinded;
+
+#[derive(Kinded)]
+#[kinded(kind = BeverageKind, external, display = "snake_case")]
+enum Drink {
+    Mate,
+    Coffee(String),
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use kinded::Kinded;
+
+#[derive(Kinded)]
+#[kinded(external)]
+enum Drink {
+    Mate,
+    Coffee(String),
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use kinded::Kinded;
+
+#[derive(Kinded)]
+#[kinded(fast_kind)]
+#[repr(u8)]
+enum Drink {
+    Mate = 1,
+    Coffee(String) = 2,
+}
+
+fn main() {}
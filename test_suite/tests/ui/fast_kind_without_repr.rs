@@ -0,0 +1,10 @@
+use kinded::Kinded;
+
+#[derive(Kinded)]
+#[kinded(fast_kind)]
+enum Drink {
+    Mate,
+    Coffee(String),
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use kinded::Kinded;
+
+#[derive(Kinded)]
+#[kinded(kind = Drink)]
+enum Drink {
+    Mate,
+    Coffee(String),
+}
+
+fn main() {}
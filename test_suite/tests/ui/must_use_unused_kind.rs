@@ -0,0 +1,14 @@
+#![deny(unused_must_use)]
+
+use kinded::Kinded;
+
+#[derive(Kinded)]
+#[kinded(must_use)]
+enum Drink {
+    Mate,
+    Coffee(String),
+}
+
+fn main() {
+    Drink::Mate.kind();
+}
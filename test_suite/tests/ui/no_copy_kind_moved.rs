@@ -0,0 +1,14 @@
+use kinded::Kinded;
+
+#[derive(Kinded)]
+#[kinded(no_derive(Copy))]
+enum Drink {
+    Mate,
+    Coffee(String),
+}
+
+fn main() {
+    let kind = Drink::Mate.kind();
+    let moved = kind;
+    assert_eq!(kind, moved);
+}
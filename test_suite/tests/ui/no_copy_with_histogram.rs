@@ -0,0 +1,10 @@
+use kinded::Kinded;
+
+#[derive(Kinded)]
+#[kinded(no_derive(Copy), histogram)]
+enum Drink {
+    Mate,
+    Coffee(String),
+}
+
+fn main() {}
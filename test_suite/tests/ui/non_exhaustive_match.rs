@@ -0,0 +1,13 @@
+use test_suite::non_exhaustive::{DrinkKind, MealKind};
+
+fn main() {
+    match DrinkKind::Mate {
+        DrinkKind::Mate => (),
+        DrinkKind::Tea => (),
+    }
+
+    match MealKind::Dinner {
+        MealKind::Breakfast => (),
+        MealKind::Dinner => (),
+    }
+}
@@ -0,0 +1,10 @@
+use kinded::Kinded;
+
+#[derive(Kinded)]
+#[kinded(parse_case_insensitive)]
+enum Db {
+    MySql,
+    MySQL,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use kinded::Kinded;
+
+#[allow(non_camel_case_types)]
+#[derive(Kinded)]
+#[kinded(prefix = "S")]
+enum elf {
+    Mate,
+    Coffee(String),
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use kinded::Kinded;
+
+#[derive(Kinded)]
+#[kinded(prefix = "1")]
+enum Drink {
+    Mate,
+    Coffee(String),
+}
+
+fn main() {}
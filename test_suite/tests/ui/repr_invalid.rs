@@ -0,0 +1,10 @@
+use kinded::Kinded;
+
+#[derive(Kinded)]
+#[kinded(repr = f32)]
+enum Drink {
+    Mate,
+    Tea,
+}
+
+fn main() {}
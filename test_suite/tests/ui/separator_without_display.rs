@@ -0,0 +1,10 @@
+use kinded::Kinded;
+
+#[derive(Kinded)]
+#[kinded(separator = ".")]
+enum Drink {
+    HotMate,
+    Coffee(String),
+}
+
+fn main() {}
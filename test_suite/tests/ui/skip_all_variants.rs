@@ -0,0 +1,11 @@
+use kinded::Kinded;
+
+#[derive(Kinded)]
+enum Drink {
+    #[kinded(skip)]
+    Mate,
+    #[kinded(skip)]
+    Tea,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use kinded::Kinded;
+
+#[derive(Kinded)]
+enum Drink {
+    #[kinded(id = 1)]
+    Mate,
+    #[kinded(id = 1)]
+    Coffee(String),
+}
+
+fn main() {}
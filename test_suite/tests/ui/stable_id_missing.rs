@@ -0,0 +1,10 @@
+use kinded::Kinded;
+
+#[derive(Kinded)]
+enum Drink {
+    #[kinded(id = 1)]
+    Mate,
+    Coffee(String),
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use kinded::Kinded;
+
+#[derive(Kinded)]
+#[kinded(suffix = "²")]
+enum Drink {
+    Mate,
+    Coffee(String),
+}
+
+fn main() {}
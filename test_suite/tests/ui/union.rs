@@ -0,0 +1,9 @@
+use kinded::Kinded;
+
+#[derive(Kinded)]
+union Number {
+    int: u32,
+    float: f32,
+}
+
+fn main() {}
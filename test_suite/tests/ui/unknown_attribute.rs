@@ -0,0 +1,10 @@
+use kinded::Kinded;
+
+#[derive(Kinded)]
+#[kinded(dsiplay = "snake_case")]
+enum Drink {
+    Mate,
+    Coffee,
+}
+
+fn main() {}
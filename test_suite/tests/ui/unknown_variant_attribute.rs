@@ -0,0 +1,10 @@
+use kinded::Kinded;
+
+#[derive(Kinded)]
+enum Drink {
+    #[kinded(ignore)]
+    Mate,
+    Coffee,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use kinded::Kinded;
+
+#[derive(Kinded)]
+#[kinded(serde, serde_repr)]
+enum Drink {
+    Mate,
+    Coffee(String),
+}
+
+fn main() {}